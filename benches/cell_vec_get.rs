@@ -0,0 +1,46 @@
+//! Measures the throughput of [`CellVec::get()`] whilst multiple threads read concurrently.
+//!
+//! Run with & without the `arc_swap` feature to compare reading the array with & without a lock.
+
+use std::{thread, time::Instant};
+
+use cell_memory::vec::CellVec;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// The amount of threads reading concurrently.
+const THREADS: u64 = 8;
+/// The amount of values within the benchmarked `CellVec`.
+const SIZE: usize = 1_000;
+
+fn get(c: &mut Criterion) {
+    let cell_vec = CellVec::new();
+    (0..SIZE as u64).for_each(|num| cell_vec.push(num));
+
+    let name = match cfg!(feature = "arc_swap") {
+        true => "arc_swap",
+        false => "mutex",
+    };
+
+    c.bench_function(&format!("cell_vec_get_contended/{name}"), |b| {
+        b.iter_custom(|iters| {
+            // Each thread performs an equal share of the reads.
+            let reads = iters.div_ceil(THREADS);
+            let start = Instant::now();
+
+            thread::scope(|scope| {
+                for _ in 0..THREADS {
+                    scope.spawn(|| {
+                        for index in 0..reads {
+                            black_box(cell_vec.get(index as usize % SIZE));
+                        }
+                    });
+                }
+            });
+
+            start.elapsed()
+        })
+    });
+}
+
+criterion_group!(benches, get);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+//! Compares the collection helpers & [`Da::write_with()`] against the equivalent [`Da::mutate()`] calls.
+
+use cell_memory::data_access::Da;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::io::Write;
+
+/// The amount of items within the benchmarked collections.
+const SIZE: usize = 10_000;
+
+fn push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push");
+
+    group.bench_function("mutate", |b| {
+        let da = Da::new(vec![0usize; SIZE]);
+        b.iter(|| {
+            da.mutate(|mut vec| {
+                vec.push(black_box(1));
+                vec
+            })
+        })
+    });
+
+    group.bench_function("push_into", |b| {
+        let da = Da::new(vec![0usize; SIZE]);
+        b.iter(|| da.push_into(black_box(1)))
+    });
+
+    // An outstanding reference forces the `Vec` to be cloned, the same as `mutate`.
+    group.bench_function("push_into_referenced", |b| {
+        let da = Da::new(vec![0usize; SIZE]);
+        b.iter(|| {
+            let _reference = da.get();
+            da.push_into(black_box(1))
+        })
+    });
+
+    group.finish();
+}
+
+fn write(c: &mut Criterion) {
+    let mut group = c.benchmark_group("write");
+
+    group.bench_function("mutate", |b| {
+        let da = Da::new(vec![0u8; SIZE]);
+        b.iter(|| {
+            da.mutate(|mut vec| {
+                vec.write_all(black_box(b"data")).unwrap();
+                vec
+            })
+        })
+    });
+
+    group.bench_function("write_with", |b| {
+        let da = Da::new(vec![0u8; SIZE]);
+        b.iter(|| da.write_with(|vec| vec.write_all(black_box(b"data")).unwrap()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, push, write);
+criterion_main!(benches);
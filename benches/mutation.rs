@@ -0,0 +1,40 @@
+//! Compares [`Da::mutate_in_place()`] against [`Da::mutate()`] for a large value.
+
+use cell_memory::data_access::Da;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// The amount of items within the benchmarked value.
+const SIZE: usize = 100_000;
+
+fn mutate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mutate_large_value");
+
+    group.bench_function("mutate", |b| {
+        let da = Da::new(vec![0u64; SIZE]);
+        b.iter(|| {
+            da.mutate(|mut vec| {
+                vec[0] += black_box(1);
+                vec
+            })
+        })
+    });
+
+    group.bench_function("mutate_in_place", |b| {
+        let da = Da::new(vec![0u64; SIZE]);
+        b.iter(|| da.mutate_in_place(|vec| vec[0] += black_box(1)))
+    });
+
+    // An outstanding reference forces the value to be cloned, the same as `mutate`.
+    group.bench_function("mutate_in_place_referenced", |b| {
+        let da = Da::new(vec![0u64; SIZE]);
+        b.iter(|| {
+            let _reference = da.get();
+            da.mutate_in_place(|vec| vec[0] += black_box(1))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, mutate);
+criterion_main!(benches);
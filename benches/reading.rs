@@ -0,0 +1,38 @@
+//! Compares [`Da::borrow_arc()`] against [`Da::get()`] for frequent reads of a small value.
+
+use cell_memory::data_access::Da;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// The amount of reads performed per iteration.
+const READS: u64 = 1_000;
+
+fn read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("read_small_value");
+
+    group.bench_function("get", |b| {
+        let da = Da::new(1u64);
+        b.iter(|| {
+            let mut sum = 0;
+            for _ in 0..READS {
+                sum += *black_box(da.get());
+            }
+            sum
+        })
+    });
+
+    group.bench_function("borrow_arc", |b| {
+        let da = Da::new(1u64);
+        b.iter(|| {
+            let mut sum = 0;
+            for _ in 0..READS {
+                sum += da.borrow_arc(|arc| **black_box(arc));
+            }
+            sum
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read);
+criterion_main!(benches);
@@ -0,0 +1,448 @@
+//! Contains [`CellDeque`], a double-ended queue built on top of [`Da`] & [`Oda`].
+
+use std::sync::Arc;
+
+use crate::{
+    data_access::{locking_mutate::Lock, Da, Oda},
+    locking_mutate,
+};
+
+/// A double-ended queue, which can be read from & written to "concurrently".
+///
+/// The elements are stored within a ring buffer, which doubles in size when it's full.
+pub struct CellDeque<Value>
+where
+    Value: 'static,
+{
+    /// The index within the array of the front element.
+    head: Da<usize>,
+    /// The amount of elements currently stored.
+    len: Da<usize>,
+    /// The allocated capacity of the array on the heap.
+    capacity: Da<usize>,
+    /// The ring buffer which stores the current values.
+    array: Da<Box<[Oda<Value>]>>,
+}
+
+impl<Value> Clone for CellDeque<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`CellDeque`] pointing to the **exact same** values as the original [`CellDeque`].
+    fn clone(&self) -> Self {
+        CellDeque {
+            head: self.head.clone(),
+            len: self.len.clone(),
+            capacity: self.capacity.clone(),
+            array: self.array.clone(),
+        }
+    }
+}
+
+impl<Value> Default for CellDeque<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new()`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Value> CellDeque<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`CellDeque<Value>`] with 0 capacity.
+    pub fn new() -> Self {
+        Self {
+            head: Da::new(0),
+            len: Da::new(0),
+            capacity: Da::new(0),
+            array: Da::new(Box::new([])),
+        }
+    }
+
+    /// Returns the amount of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.len.copy_value()
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Holds the locks on the head, length & array whilst calling the given function with their
+    /// values. The locks are acquired in the same order as [`locking_mutate!`] is called with.
+    fn read<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(usize, usize, &[Oda<Value>]) -> Output,
+    {
+        let head = Lock::lock(&self.head);
+        let len = Lock::lock(&self.len);
+        let array = Lock::lock(&self.array);
+        func(**head, **len, &array)
+    }
+
+    /// Returns the value at the given index, counting from the front of the deque.
+    /// If the given index is outside the bounds of the deque None is returned.
+    pub fn get(&self, index: usize) -> Option<Arc<Value>> {
+        self.read(|head, len, array| {
+            if index >= len {
+                return None;
+            }
+            array[(head + index) % array.len()].get()
+        })
+    }
+
+    /// Returns the value at the front of the deque, or None if it's empty.
+    pub fn peek_front(&self) -> Option<Arc<Value>> {
+        self.get(0)
+    }
+
+    /// Returns the value at the back of the deque, or None if it's empty.
+    pub fn peek_back(&self) -> Option<Arc<Value>> {
+        self.read(|head, len, array| {
+            if len == 0 {
+                return None;
+            }
+            array[(head + len - 1) % array.len()].get()
+        })
+    }
+
+    /// Doubles the size of the given ring buffer, moving the front element to the start of the buffer.
+    /// Returns the new head, capacity & ring buffer.
+    fn grow(
+        head: usize,
+        len: usize,
+        capacity: usize,
+        array: &[Oda<Value>],
+    ) -> (usize, usize, Box<[Oda<Value>]>) {
+        // Clones every existing value, in order from front to back.
+        let existing_iter = (0..len).map(|index| array[(head + index) % capacity].clone());
+
+        // Creates new default Oda's to pad the array.
+        let new_capacity = (capacity << 1).max(1);
+        let default_iter = (len..new_capacity).map(|_| -> Oda<Value> { Oda::default() });
+
+        (0, new_capacity, existing_iter.chain(default_iter).collect())
+    }
+
+    /// Appends the given value to the back of the deque.
+    pub fn push_back(&self, new_value: Value) {
+        let closure =
+            |mut head: usize, len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
+                if len >= capacity {
+                    (head, capacity, array) = Self::grow(head, len, capacity, &array);
+                }
+
+                array[(head + len) % capacity].set(new_value);
+                (head, len + 1, capacity, array)
+            };
+
+        let (head, len, capacity, array) = (
+            self.head.clone(),
+            self.len.clone(),
+            self.capacity.clone(),
+            self.array.clone(),
+        );
+
+        locking_mutate!(head, len, capacity, array; closure);
+    }
+
+    /// Prepends the given value to the front of the deque.
+    pub fn push_front(&self, new_value: Value) {
+        let closure =
+            |mut head: usize, len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
+                if len >= capacity {
+                    (head, capacity, array) = Self::grow(head, len, capacity, &array);
+                }
+
+                head = (head + capacity - 1) % capacity;
+                array[head].set(new_value);
+                (head, len + 1, capacity, array)
+            };
+
+        let (head, len, capacity, array) = (
+            self.head.clone(),
+            self.len.clone(),
+            self.capacity.clone(),
+            self.array.clone(),
+        );
+
+        locking_mutate!(head, len, capacity, array; closure);
+    }
+
+    /// Removes & returns the value at the front of the deque, or None if it's empty.
+    pub fn pop_front(&self) -> Option<Arc<Value>> {
+        let mut popped = None;
+
+        let mut closure = |mut head: usize, mut len: usize, array: Box<[Oda<Value>]>| {
+            if len > 0 {
+                popped = array[head].take();
+                head = (head + 1) % array.len();
+                len -= 1;
+            }
+            (head, len, array)
+        };
+
+        let (head, len, array) = (self.head.clone(), self.len.clone(), self.array.clone());
+
+        locking_mutate!(head, len, array; closure);
+
+        popped
+    }
+
+    /// Removes & returns the value at the back of the deque, or None if it's empty.
+    pub fn pop_back(&self) -> Option<Arc<Value>> {
+        let mut popped = None;
+
+        let mut closure = |head: usize, mut len: usize, array: Box<[Oda<Value>]>| {
+            if len > 0 {
+                popped = array[(head + len - 1) % array.len()].take();
+                len -= 1;
+            }
+            (head, len, array)
+        };
+
+        let (head, len, array) = (self.head.clone(), self.len.clone(), self.array.clone());
+
+        locking_mutate!(head, len, array; closure);
+
+        popped
+    }
+
+    /// Returns an iterator over the values in the deque, from front to back.
+    ///
+    /// The length of the deque is read when this method is called. Each value is then read when
+    /// the iterator reaches it, so any values removed in the meantime will end the iteration early.
+    pub fn iter(&self) -> CellDequeIterator<Value> {
+        CellDequeIterator {
+            cell_deque: self.clone(),
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// Returns an iterator over the values in the deque, from back to front.
+    ///
+    /// See [`Self::iter()`] for more information.
+    pub fn iter_rev(&self) -> std::iter::Rev<CellDequeIterator<Value>> {
+        self.iter().rev()
+    }
+}
+
+/// An iterator over the values of a [`CellDeque`].
+pub struct CellDequeIterator<Value>
+where
+    Value: 'static,
+{
+    cell_deque: CellDeque<Value>,
+    /// The index of the next value from the front.
+    front: usize,
+    /// One past the index of the next value from the back.
+    back: usize,
+}
+
+impl<Value> Iterator for CellDequeIterator<Value>
+where
+    Value: 'static,
+{
+    type Item = Arc<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let value = self.cell_deque.get(self.front);
+        self.front += 1;
+        value
+    }
+}
+
+impl<Value> DoubleEndedIterator for CellDequeIterator<Value>
+where
+    Value: 'static,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.cell_deque.get(self.back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::test_data::Data;
+
+    use super::*;
+
+    /// Pushes the given amount of values to the back of a new deque.
+    fn populate(size: usize) -> CellDeque<Data> {
+        let cell_deque = CellDeque::new();
+
+        for num in 0..size as i32 {
+            cell_deque.push_back(Data::new(num));
+        }
+
+        // Sanity check
+        assert_eq!(cell_deque.len(), size);
+        cell_deque
+    }
+
+    #[test]
+    fn empty() {
+        let cell_deque: CellDeque<Data> = CellDeque::new();
+        assert!(cell_deque.is_empty());
+        assert!(cell_deque.peek_front().is_none());
+        assert!(cell_deque.peek_back().is_none());
+        assert!(cell_deque.pop_front().is_none());
+        assert!(cell_deque.pop_back().is_none());
+        assert!(cell_deque.get(0).is_none());
+    }
+
+    #[test]
+    fn push_back() {
+        let cell_deque = populate(5);
+
+        for num in 0..5 {
+            assert_eq!(*cell_deque.get(num as usize).unwrap(), num.into());
+        }
+        assert_eq!(*cell_deque.peek_front().unwrap(), 0.into());
+        assert_eq!(*cell_deque.peek_back().unwrap(), 4.into());
+    }
+
+    #[test]
+    fn push_front() {
+        let cell_deque = CellDeque::new();
+        for num in 0..5 {
+            cell_deque.push_front(Data::new(num));
+        }
+
+        for num in 0..5 {
+            assert_eq!(*cell_deque.get(num as usize).unwrap(), (4 - num).into());
+        }
+    }
+
+    #[test]
+    fn pop() {
+        let cell_deque = populate(4);
+
+        assert_eq!(*cell_deque.pop_front().unwrap(), 0.into());
+        assert_eq!(*cell_deque.pop_back().unwrap(), 3.into());
+        assert_eq!(cell_deque.len(), 2);
+
+        assert_eq!(*cell_deque.peek_front().unwrap(), 1.into());
+        assert_eq!(*cell_deque.peek_back().unwrap(), 2.into());
+
+        assert_eq!(*cell_deque.pop_back().unwrap(), 2.into());
+        assert_eq!(*cell_deque.pop_front().unwrap(), 1.into());
+        assert!(cell_deque.is_empty());
+        assert!(cell_deque.pop_front().is_none());
+    }
+
+    #[test]
+    /// Values must remain in order when the head wraps around the end of the ring buffer.
+    fn wraps() {
+        let cell_deque = populate(4);
+        assert_eq!(cell_deque.capacity.copy_value(), 4);
+
+        cell_deque.pop_front();
+        cell_deque.pop_front();
+        cell_deque.push_back(Data::new(4));
+        cell_deque.push_back(Data::new(5));
+        // The ring buffer is full, but hasn't grown.
+        assert_eq!(cell_deque.capacity.copy_value(), 4);
+
+        // Growing must preserve the order of the wrapped values.
+        cell_deque.push_back(Data::new(6));
+        cell_deque.push_front(Data::new(1));
+        assert_eq!(cell_deque.capacity.copy_value(), 8);
+
+        let values: Vec<_> = cell_deque.iter().collect();
+        assert_eq!(values.len(), 6);
+        for (value, expected) in values.into_iter().zip(1..) {
+            assert_eq!(*value, expected.into());
+        }
+    }
+
+    #[test]
+    fn grows() {
+        let cell_deque = CellDeque::new();
+        assert_eq!(cell_deque.capacity.copy_value(), 0);
+
+        cell_deque.push_front(Data::default());
+        assert_eq!(cell_deque.capacity.copy_value(), 1);
+
+        cell_deque.push_back(Data::default());
+        assert_eq!(cell_deque.capacity.copy_value(), 2);
+
+        cell_deque.push_front(Data::default());
+        assert_eq!(cell_deque.capacity.copy_value(), 4);
+        cell_deque.push_back(Data::default());
+        assert_eq!(cell_deque.capacity.copy_value(), 4);
+
+        cell_deque.push_back(Data::default());
+        assert_eq!(cell_deque.capacity.copy_value(), 8);
+    }
+
+    #[test]
+    fn iterators() {
+        let cell_deque = populate(4);
+
+        let forwards: Vec<_> = cell_deque.iter().collect();
+        let backwards: Vec<_> = cell_deque.iter_rev().collect();
+
+        assert_eq!(forwards.len(), 4);
+        assert_eq!(backwards.len(), 4);
+        for num in 0..4 {
+            assert_eq!(*forwards[num], (num as i32).into());
+            assert_eq!(*backwards[num], (3 - num as i32).into());
+        }
+    }
+
+    #[test]
+    /// Clones must share the same state.
+    fn clone_shares_state() {
+        let cell_deque = populate(2);
+        let clone = cell_deque.clone();
+
+        clone.push_back(Data::new(2));
+        assert_eq!(cell_deque.len(), 3);
+        assert_eq!(*cell_deque.peek_back().unwrap(), 2.into());
+    }
+
+    #[test]
+    fn concurrent_push() {
+        let cell_deque = CellDeque::new();
+
+        let handles: Vec<_> = (0..4)
+            .map(|thread_num| {
+                let cell_deque = cell_deque.clone();
+                thread::spawn(move || {
+                    for num in 0..50 {
+                        if thread_num % 2 == 0 {
+                            cell_deque.push_back(Data::new(num));
+                        } else {
+                            cell_deque.push_front(Data::new(num));
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(cell_deque.len(), 200);
+        assert_eq!(cell_deque.iter().count(), 200);
+    }
+}
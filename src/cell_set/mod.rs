@@ -0,0 +1,250 @@
+//! Contains the [`CellSet`], a set backed by a [`CellHashMap`].
+
+use std::{fmt::Debug, hash::Hash};
+
+use crate::hash::{CellHashMap, CellHashMapIter};
+
+/// A set of unique keys which can be "concurrently" read & written to.
+///
+/// This is a thin wrapper around a [`CellHashMap`] with `()` values.
+pub struct CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    map: CellHashMap<Key, ()>,
+}
+
+impl<Key> CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    /// Creates a new, empty [`CellSet`].
+    pub fn new() -> Self {
+        Self {
+            map: CellHashMap::new(),
+        }
+    }
+
+    /// Adds the given key to the set, returning `true` if it wasn't already present.
+    ///
+    /// If multiple threads insert the same key concurrently, only one of them will return `true`.
+    pub fn insert(&self, key: Key) -> bool {
+        self.map.put(key, ()).is_none()
+    }
+
+    /// Removes the given key from the set, returning `true` if it was present.
+    pub fn remove(&self, key: &Key) -> bool {
+        self.map.remove(key.clone()).is_some()
+    }
+
+    /// Returns `true` if the set contains the given key.
+    pub fn contains(&self, key: &Key) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the amount of keys in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the keys in the set, in no particular order.
+    ///
+    /// See [`CellHashMap::iter()`] for the behaviour under concurrent modification.
+    pub fn iter(&self) -> CellSetIter<'_, Key> {
+        CellSetIter {
+            iter: self.map.iter(),
+        }
+    }
+
+    /// Removes every key from the set.
+    pub fn clear(&self) {
+        self.map.clear();
+    }
+
+    /// Removes every key the given function returns `false` for.
+    ///
+    /// See [`CellHashMap::retain()`] for the locking behaviour.
+    pub fn retain<Func>(&self, func: Func)
+    where
+        Func: Fn(&Key) -> bool,
+    {
+        self.map.retain(|key, _| func(key));
+    }
+}
+
+impl<Key> Clone for CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    /// Creates a new [`CellSet`] pointing to the **exact same** keys as the original [`CellSet`].
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<Key> Debug for CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<Key> Default for CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    /// Idiomatic to calling [`Self::new()`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Key> FromIterator<Key> for CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    fn from_iter<Iter: IntoIterator<Item = Key>>(iter: Iter) -> Self {
+        Self {
+            map: iter.into_iter().map(|key| (key, ())).collect(),
+        }
+    }
+}
+
+impl<Key> PartialEq for CellSet<Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    /// Returns `true` if both sets contain the same keys.
+    ///
+    /// The sets are read without being locked, so a concurrent modification to either set could
+    /// result in an inconsistent comparison.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|key| other.contains(&key))
+    }
+}
+
+/// An iterator over the keys of a [`CellSet`], created by [`CellSet::iter()`].
+pub struct CellSetIter<'a, Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    iter: CellHashMapIter<'a, Key, ()>,
+}
+
+impl<Key> Iterator for CellSetIter<'_, Key>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+{
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| (*key).clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        sync::{Arc, Barrier},
+        thread,
+    };
+
+    use super::*;
+
+    #[test]
+    fn insert_and_remove() {
+        let cell_set = CellSet::new();
+        assert!(cell_set.is_empty());
+
+        assert!(cell_set.insert(1));
+        assert!(!cell_set.insert(1));
+        assert!(cell_set.insert(2));
+        assert_eq!(cell_set.len(), 2);
+
+        assert!(cell_set.contains(&1));
+        assert!(cell_set.remove(&1));
+        assert!(!cell_set.remove(&1));
+        assert!(!cell_set.contains(&1));
+        assert_eq!(cell_set.len(), 1);
+    }
+
+    #[test]
+    fn iter_retain_and_clear() {
+        let cell_set: CellSet<u32> = (0..100).collect();
+        assert_eq!(cell_set.iter().collect::<HashSet<_>>(), (0..100).collect());
+
+        cell_set.retain(|key| key % 2 == 0);
+        assert_eq!(cell_set.len(), 50);
+        assert!(cell_set.contains(&2));
+        assert!(!cell_set.contains(&3));
+
+        cell_set.clear();
+        assert!(cell_set.is_empty());
+    }
+
+    #[test]
+    fn equality_and_debug() {
+        let first: CellSet<u32> = [1, 2, 3].into_iter().collect();
+        let second: CellSet<u32> = [3, 2, 1].into_iter().collect();
+        assert_eq!(first, second);
+
+        second.remove(&3);
+        assert_ne!(first, second);
+
+        let single: CellSet<u32> = [1].into_iter().collect();
+        assert_eq!(format!("{single:?}"), "{1}");
+    }
+
+    #[test]
+    /// Clones must share the same keys.
+    fn clone_shares_state() {
+        let cell_set = CellSet::new();
+        let clone = cell_set.clone();
+
+        clone.insert("a");
+        assert!(cell_set.contains(&"a"));
+    }
+
+    #[test]
+    /// Each key must only be reported as newly inserted or removed by a single thread.
+    fn concurrent_insert_and_remove() {
+        const THREADS: usize = 8;
+
+        let cell_set = CellSet::new();
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let spawn = |func: fn(&CellSet<usize>, usize) -> bool| {
+            (0..THREADS)
+                .map(|_| {
+                    let cell_set = cell_set.clone();
+                    let barrier = barrier.clone();
+                    thread::spawn(move || {
+                        barrier.wait();
+                        (0..1000).filter(|key| func(&cell_set, *key)).count()
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .sum::<usize>()
+        };
+
+        let inserted = spawn(|cell_set, key| cell_set.insert(key));
+        assert_eq!(inserted, 1000);
+        assert_eq!(cell_set.len(), 1000);
+
+        let removed = spawn(|cell_set, key| cell_set.remove(&key));
+        assert_eq!(removed, 1000);
+        assert!(cell_set.is_empty());
+    }
+}
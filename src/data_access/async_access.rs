@@ -0,0 +1,528 @@
+//! Contains async variants of [`Da`] & [`Oda`], along with the [`async_locking_mutate`] macro.
+//!
+//! Holding a [`std::sync::Mutex`] across an `.await` point can dead-lock the executor, so these
+//! structs are backed by [`tokio::sync::Mutex`] instead, which yields to other tasks whilst waiting
+//! to acquire the lock.
+
+// Used in docs
+#[allow(unused_imports)]
+use crate::{
+    async_locking_mutate,
+    data_access::{Da, Oda},
+};
+
+use std::{future::Future, sync::Arc};
+use tokio::sync::{Mutex, MutexGuard};
+
+/// [`AsyncOptionalDataAccess`](AsyncOda)
+/// ---
+///
+/// The async equivalent of [`Oda`].
+pub struct AsyncOda<Value>
+where
+    Value: 'static,
+{
+    /// Contains the data being represented.
+    /// ---
+    ///
+    /// See [`Oda`] for the reasoning behind the layout.
+    pub(super) current_ref: Arc<Mutex<Option<Arc<Value>>>>,
+}
+
+impl<Value> AsyncOda<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`AsyncOda<Value>`].
+    pub fn new(data: Value) -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(Some(Arc::new(data)))),
+        }
+    }
+
+    /// Creates a new [`AsyncOda<Value>`] which references the given [`Arc`].
+    pub fn acquire(value_reference: Arc<Value>) -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(Some(value_reference))),
+        }
+    }
+
+    /// Gets a reference to the current underlying data.
+    ///
+    /// This reference **will be uneffected** by any subsequent mutations.
+    pub async fn get(&self) -> Option<Arc<Value>> {
+        self.current_ref.lock().await.clone()
+    }
+
+    /// Allows for a value that implements [`Copy`] to be copied out of [`AsyncOda`]. (If a value is present).
+    ///
+    /// This copy is in no way related to the underlying data other than by it's value of the time
+    /// of copying.
+    pub async fn copy_value(&self) -> Option<Value>
+    where
+        Value: Copy,
+    {
+        self.current_ref
+            .lock()
+            .await
+            .as_ref()
+            .map(|arc_ref| **arc_ref)
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value.
+    ///
+    /// See [`Oda::set()`] for more information on the behaviour of current & future references.
+    pub async fn set(&self, new_data: Value) -> Option<Arc<Value>> {
+        self.current_ref.lock().await.replace(Arc::new(new_data))
+    }
+
+    /// Sets the underlying data to the given [`Arc`], or to no data if `None` is given; Returning
+    /// the old value.
+    ///
+    /// See [`Oda::set_arc()`] for more information.
+    pub async fn set_arc(&self, data_arc: Option<Arc<Value>>) -> Option<Arc<Value>> {
+        std::mem::replace(&mut *self.current_ref.lock().await, data_arc)
+    }
+
+    /// Replaces the the [`Arc`] contained within [`Self`] to the given [`Arc`].
+    ///
+    /// See [`Oda::set_arc()`] for more information.
+    #[deprecated(note = "Renamed to `set_arc`, to match `set`")]
+    pub async fn replace(&self, data_arc: Option<Arc<Value>>) {
+        self.set_arc(data_arc).await;
+    }
+
+    /// Takes the value out of the [`AsyncOda`], leaving `None` in its place.
+    pub async fn take(&self) -> Option<Arc<Value>> {
+        self.current_ref.lock().await.take()
+    }
+
+    /// If there is underlying data, it's cloned & the given function will be called with it as the parameter.
+    /// The value returned from the function will be set as the new underlying data.
+    ///
+    /// See [`Oda::mutate()`] for more information, including the locking behaviour.
+    pub async fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        if let Some(old_value) = self.get().await {
+            let mutated_value = func((*old_value).clone());
+            self.set(mutated_value).await;
+        }
+    }
+}
+
+/// [`AsyncDataAccess`](AsyncDa)
+/// ---
+///
+/// The async equivalent of [`Da`].
+pub struct AsyncDa<Value>
+where
+    Value: 'static,
+{
+    /// Contains the data being represented.
+    /// ---
+    ///
+    /// See [`Da`] for the reasoning behind the layout.
+    pub(super) current_ref: Arc<Mutex<Arc<Value>>>,
+}
+
+impl<Value> AsyncDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`AsyncDa<Value>`].
+    pub fn new(data: Value) -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(Arc::new(data))),
+        }
+    }
+
+    /// Creates a new [`AsyncDa<Value>`] which references the given [`Arc`].
+    pub fn acquire(value_reference: Arc<Value>) -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(value_reference)),
+        }
+    }
+
+    /// Gets a reference to the current underlying data.
+    ///
+    /// This reference **will be uneffected** by any subsequent mutations.
+    pub async fn get(&self) -> Arc<Value> {
+        self.current_ref.lock().await.clone()
+    }
+
+    /// Allows for a value that implements [`Copy`] to be copied out of [`AsyncDa`].
+    ///
+    /// This copy is in no way related to the underlying data other than by it's value of the time
+    /// of copying.
+    pub async fn copy_value(&self) -> Value
+    where
+        Value: Copy,
+    {
+        **self.current_ref.lock().await
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value.
+    ///
+    /// See [`Da::set()`] for more information on the behaviour of current & future references.
+    pub async fn set(&self, new_data: Value) -> Arc<Value> {
+        let mut data = self.current_ref.lock().await;
+        std::mem::replace(&mut *data, Arc::new(new_data))
+    }
+
+    /// Sets the underlying data to the given [`Arc`]; Returning the old value.
+    ///
+    /// See [`Da::set_arc()`] for more information.
+    pub async fn set_arc(&self, data_arc: Arc<Value>) -> Arc<Value> {
+        std::mem::replace(&mut *self.current_ref.lock().await, data_arc)
+    }
+
+    /// Replaces the the [`Arc`] contained within [`Self`] to the given [`Arc`].
+    ///
+    /// See [`Da::set_arc()`] for more information.
+    #[deprecated(note = "Renamed to `set_arc`, to match `set`")]
+    pub async fn replace(&self, data_arc: Arc<Value>) {
+        self.set_arc(data_arc).await;
+    }
+
+    /// Clones the existing underlying data & calls the given function with the clone as the parameter.
+    /// The value returned from the function will be set as the new underlying data.
+    ///
+    /// See [`Da::mutate()`] for more information, including the locking behaviour.
+    pub async fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        let mutated_value = func((*self.get().await).clone());
+        self.set(mutated_value).await;
+    }
+}
+
+impl<Value> Default for AsyncOda<Value>
+where
+    Value: 'static,
+{
+    /// Creates an [`AsyncOda`] containing `None`.
+    fn default() -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl<Value> From<Value> for AsyncOda<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Value> Clone for AsyncOda<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`AsyncOda`] pointing to the **exact same** value as the original [`AsyncOda`].
+    fn clone(&self) -> Self {
+        Self {
+            current_ref: self.current_ref.clone(),
+        }
+    }
+}
+
+impl<Value> Default for AsyncDa<Value>
+where
+    Value: Default + 'static,
+{
+    /// Idiomatic to calling [`Self::new(value::default())`](Self::new()).
+    fn default() -> Self {
+        Self::new(Value::default())
+    }
+}
+
+impl<Value> From<Value> for AsyncDa<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Value> Clone for AsyncDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`AsyncDa`] pointing to the **exact same** value as the original [`AsyncDa`].
+    fn clone(&self) -> Self {
+        Self {
+            current_ref: self.current_ref.clone(),
+        }
+    }
+}
+
+#[macro_export]
+/**
+The async equivalent of [`locking_mutate`](crate::locking_mutate), operating on [`AsyncDa`] &
+[`AsyncOda`] structs.
+
+Each lock is acquired by `.await`ing it, so other tasks can make progress whilst waiting. The given
+closure must return a future, which is `.await`ed whilst the locks are held.
+
+# Examples
+
+```
+use cell_memory::{
+    async_locking_mutate,
+    data_access::async_access::{AsyncDa, AsyncOda},
+};
+
+# tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+let score = AsyncDa::new(5);
+let highscore = AsyncOda::new(8);
+
+async_locking_mutate!(score, highscore; async |score: u64, highscore: Option<u64>| {
+    // Other tasks can run whilst this closure is awaiting.
+    tokio::task::yield_now().await;
+    (score + 1, highscore.map(|highscore| highscore.max(score + 1)))
+});
+
+assert_eq!(score.copy_value().await, 6);
+assert_eq!(highscore.copy_value().await, Some(8));
+# });
+```
+
+When only a single AsyncDa or AsyncOda is given, the closure takes & returns the value directly,
+rather than as a tuple.
+
+```
+use cell_memory::{async_locking_mutate, data_access::async_access::AsyncDa};
+
+# tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+let score = AsyncDa::new(5);
+async_locking_mutate!(score; async |score: u64| score + 1);
+
+assert_eq!(score.copy_value().await, 6);
+# });
+```
+
+# Panics
+
+If the same data is given more than once, as awaiting its lock a second time would never
+complete. See [`locking_mutate`](crate::locking_mutate#panics).
+*/
+macro_rules! async_locking_mutate {
+    // A single value is passed into & returned from the closure directly, rather than as a tuple.
+    ($data_access:ident; $func:expr) => {
+        {
+            // See the multiple value case below for the reasoning behind this import.
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            let mut lock = $crate::data_access::async_access::AsyncLock::lock(&$data_access).await;
+            let modified = $func(lock.ooa()).await;
+
+            *lock = {
+                let value = Wrapper::from(modified);
+                value.into()
+            };
+        }
+    };
+    ($($data_access:ident), +; $func:expr) => {
+        {
+            // See `locking_mutate` for the reasoning behind these imports.
+            use paste::paste;
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            // Checked before awaiting any lock, as awaiting the same lock twice never completes.
+            $crate::data_access::locking_mutate::assert_distinct(
+                &[$($crate::data_access::async_access::AsyncLock::lock_address(&$data_access),)+]
+            );
+
+            // Acquires each lock in the order given.
+            $(
+                let paste!{mut [<$data_access _lock>]} =
+                    $crate::data_access::async_access::AsyncLock::lock(&$data_access).await;
+            )+
+
+            // Executes the given function/closure, awaiting the returned future.
+            let ($(paste!{[<$data_access _modified>]}, )+) = $func($(
+                paste!{[<$data_access _lock>]}.ooa(),
+            )+).await;
+
+            // Replaces the internal values with returned values from the function/closure.
+            $(
+                *paste!{[<$data_access _lock>]} = {
+                    let value = paste!{[<$data_access _modified>]};
+                    let value = Wrapper::from(value);
+                    value.into()
+                };
+            )+
+        }
+    };
+}
+
+/// Provides solitary access to data via a [`MutexGuard`], acquired asynchronously.
+///
+/// The async equivalent of [`Lock`](crate::data_access::locking_mutate::Lock).
+pub trait AsyncLock<Value> {
+    /// The value contained within the returned [`MutexGuard`].
+    type Returns: 'static;
+
+    /// Returns a [`MutexGuard`] to the underlying data represented by this struct, once the lock
+    /// has been acquired.
+    fn lock(&self) -> impl Future<Output = MutexGuard<'_, Self::Returns>> + Send;
+
+    /// Returns the address of the lock on the underlying data, which is shared between clones.
+    ///
+    /// Used by [`async_locking_mutate!`] to detect the same data being given more than once.
+    ///
+    /// As the lock can only be acquired asynchronously, the default returns the address of this
+    /// struct instead. So clones sharing a lock aren't detected, and should override this.
+    /// [`AsyncDa`] & [`AsyncOda`] return the address of their shared lock.
+    fn lock_address(&self) -> usize {
+        self as *const Self as *const () as usize
+    }
+}
+
+impl<Value> AsyncLock<Value> for AsyncOda<Value>
+where
+    Value: Send + Sync + 'static,
+{
+    type Returns = Option<Arc<Value>>;
+
+    async fn lock(&self) -> MutexGuard<'_, Self::Returns> {
+        self.current_ref.lock().await
+    }
+
+    fn lock_address(&self) -> usize {
+        Arc::as_ptr(&self.current_ref) as usize
+    }
+}
+
+impl<Value> AsyncLock<Value> for AsyncDa<Value>
+where
+    Value: Send + Sync + 'static,
+{
+    type Returns = Arc<Value>;
+
+    async fn lock(&self) -> MutexGuard<'_, Self::Returns> {
+        self.current_ref.lock().await
+    }
+
+    fn lock_address(&self) -> usize {
+        Arc::as_ptr(&self.current_ref) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::Data;
+
+    #[tokio::test]
+    async fn da_lock() {
+        let da_one = AsyncDa::new(Data::new(1));
+        let da_two = AsyncDa::new(Data::new(2));
+
+        async_locking_mutate!(da_one, da_two; async |one: Data, two: Data| {
+            (one + Data::new(1), two + Data::new(1))
+        });
+
+        assert_eq!(*da_one.get().await, 2.into());
+        assert_eq!(*da_two.get().await, 3.into());
+    }
+
+    #[tokio::test]
+    async fn oda_lock() {
+        let oda_one = AsyncOda::new(Data::new(1));
+        let oda_two: AsyncOda<Data> = AsyncOda::default();
+
+        async_locking_mutate!(oda_one, oda_two; async |one: Option<Data>, two: Option<Data>| {
+            (one.map(|value| value + 1), two.map(|value| value + 1))
+        });
+
+        assert_eq!(*oda_one.get().await.unwrap(), 2.into());
+        assert!(oda_two.get().await.is_none());
+    }
+
+    #[tokio::test]
+    /// Awaiting inside the closure must allow other tasks to progress, even on a single thread.
+    async fn await_inside_lock() {
+        let counter = AsyncDa::new(0);
+        let other = AsyncDa::new(0);
+
+        let (locked_tx, locked_rx) = tokio::sync::oneshot::channel();
+        let (release_tx, release_rx) = tokio::sync::oneshot::channel();
+
+        let counter_clone = counter.clone();
+        let holding = tokio::spawn(async move {
+            async_locking_mutate!(counter_clone; async move |count: i32| {
+                locked_tx.send(()).unwrap();
+                // Waits on another task whilst holding the lock.
+                release_rx.await.unwrap();
+                count + 1
+            });
+        });
+
+        let counter_clone = counter.clone();
+        let waiting = tokio::spawn(async move {
+            counter_clone.mutate(|count| count + 1).await;
+        });
+
+        locked_rx.await.unwrap();
+
+        // Unrelated data can still be locked whilst the other lock is held.
+        async_locking_mutate!(other; async |value: i32| value + 1);
+        assert_eq!(other.copy_value().await, 1);
+
+        release_tx.send(()).unwrap();
+        holding.await.unwrap();
+        waiting.await.unwrap();
+
+        assert_eq!(counter.copy_value().await, 2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "The same data was given more than once")]
+    async fn lock_same_data() {
+        let da = AsyncDa::new(0);
+        let da_clone = da.clone();
+        async_locking_mutate!(da, da_clone; async |one: i32, two: i32| (one, two));
+    }
+
+    #[tokio::test]
+    async fn data_access() {
+        let da = AsyncDa::new(Data::new(1));
+        let before_set = da.get().await;
+
+        assert_eq!(*da.set(Data::new(2)).await, 1.into());
+        assert_eq!(*da.get().await, 2.into());
+        // Old references remain valid.
+        assert_eq!(*before_set, 1.into());
+
+        da.mutate(|value| value + 1).await;
+        assert_eq!(*da.get().await, 3.into());
+    }
+
+    #[tokio::test]
+    async fn optional_data_access() {
+        let oda = AsyncOda::new(Data::new(1));
+
+        assert_eq!(*oda.set(Data::new(2)).await.unwrap(), 1.into());
+        oda.mutate(|value| value + 1).await;
+        assert_eq!(*oda.get().await.unwrap(), 3.into());
+
+        assert_eq!(*oda.take().await.unwrap(), 3.into());
+        assert!(oda.get().await.is_none());
+
+        // Mutating no data has no effect.
+        oda.mutate(|value| value + 1).await;
+        assert!(oda.get().await.is_none());
+    }
+}
@@ -0,0 +1,207 @@
+//! Contains the callbacks registered through [`Da::on_set()`] & [`Oda::on_set()`].
+
+// Used in docs
+#[allow(unused_imports)]
+use crate::data_access::{Da, Oda};
+
+use crate::sync::{self, Arc, Mutex, MutexGuard, OnceLock, PoisonError, Weak};
+use alloc::{boxed::Box, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A callback which is called with the new underlying data, returning `false` once it should be
+/// deregistered.
+///
+/// Each callback has its own lock, so it can be called without holding the lock on the list &
+/// without requiring it to be [`Sync`].
+type Callback<Arg> = Arc<Mutex<Box<dyn Fn(Arg) -> bool + Send>>>;
+
+/// The callbacks registered on a [`Da`] or [`Oda`], shared between its clones.
+pub(crate) struct Callbacks<Arg> {
+    /// The id given to the next registered callback.
+    next_id: usize,
+    callbacks: Vec<(usize, Callback<Arg>)>,
+}
+
+impl<Arg> Default for Callbacks<Arg> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+/// The [`Callbacks`] of a [`Da`] or [`Oda`], along with how many there are.
+pub(crate) struct CallbackList<Arg> {
+    /// The amount of registered callbacks, which is read without acquiring the lock on the list.
+    /// So changing the data doesn't acquire a second lock when no callbacks are registered.
+    len: AtomicUsize,
+    callbacks: Mutex<Callbacks<Arg>>,
+}
+
+impl<Arg> CallbackList<Arg> {
+    /// Calls the given function with the locked list, updating the amount of callbacks once it has
+    /// returned.
+    fn modify<Output>(&self, func: impl FnOnce(&mut Callbacks<Arg>) -> Output) -> Output {
+        let mut locked = lock(&self.callbacks);
+        let output = func(&mut locked);
+        self.len.store(locked.callbacks.len(), Ordering::Release);
+        output
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len.load(Ordering::Acquire) == 0
+    }
+}
+
+/// The [`CallbackList`] of a [`Da`] or [`Oda`], shared between its clones.
+pub(crate) type SharedCallbacks<Arg> = Arc<CallbackList<Arg>>;
+
+/// The [`SharedCallbacks`] of a [`Da`] or [`Oda`], which are only allocated once a callback is
+/// registered or the data access is cloned.
+///
+/// Data which is never cloned & never has a callback registered doesn't pay for the allocation.
+/// Cloning allocates them, as clones must share any callbacks registered afterwards.
+pub(crate) struct LazyCallbacks<Arg> {
+    shared: OnceLock<SharedCallbacks<Arg>>,
+}
+
+impl<Arg> LazyCallbacks<Arg> {
+    /// Returns the callbacks, allocating them if they haven't been already.
+    pub(crate) fn shared(&self) -> &SharedCallbacks<Arg> {
+        sync::get_or_init(&self.shared, || {
+            Arc::new(CallbackList {
+                len: AtomicUsize::new(0),
+                callbacks: Mutex::new(Callbacks::default()),
+            })
+        })
+    }
+
+    /// Returns the callbacks, or `None` if they haven't been allocated.
+    pub(crate) fn get(&self) -> Option<&SharedCallbacks<Arg>> {
+        self.shared.get()
+    }
+}
+
+impl<Arg> Default for LazyCallbacks<Arg> {
+    fn default() -> Self {
+        Self {
+            shared: OnceLock::new(),
+        }
+    }
+}
+
+impl<Arg> From<SharedCallbacks<Arg>> for LazyCallbacks<Arg> {
+    fn from(shared: SharedCallbacks<Arg>) -> Self {
+        let callbacks = Self::default();
+        sync::get_or_init(&callbacks.shared, || shared);
+        callbacks
+    }
+}
+
+impl<Arg> Clone for LazyCallbacks<Arg> {
+    /// Shares the callbacks with the clone, allocating them if they haven't been already.
+    fn clone(&self) -> Self {
+        Self::from(self.shared().clone())
+    }
+}
+
+/// Acquires the given lock, ignoring any poison.
+///
+/// No callback is called whilst the lock on the list is held, and the list is only ever pushed to
+/// or filtered, so it can't be left inconsistent. A poisoned callback was interrupted by its own
+/// panic, which doesn't prevent calling it again.
+fn lock<Data>(mutex: &Mutex<Data>) -> MutexGuard<'_, Data> {
+    sync::lock(mutex).unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Registers the given callback, returning the handle which deregisters it on drop.
+pub(crate) fn register<Arg, Func>(callbacks: &LazyCallbacks<Arg>, callback: Func) -> CallbackHandle
+where
+    Arg: 'static,
+    Func: Fn(Arg) + Send + 'static,
+{
+    let id = register_while(callbacks, move |arg| {
+        callback(arg);
+        true
+    });
+
+    // Held weakly, so the handle doesn't keep the callbacks alive after the data is dropped.
+    let callbacks: Weak<CallbackList<Arg>> = Arc::downgrade(callbacks.shared());
+    CallbackHandle {
+        deregister: Some(Box::new(move || {
+            if let Some(callbacks) = callbacks.upgrade() {
+                callbacks.modify(|locked| {
+                    locked
+                        .callbacks
+                        .retain(|(callback_id, _)| *callback_id != id)
+                });
+            }
+        })),
+    }
+}
+
+/// Registers the given callback without a handle, returning its id. The callback is deregistered
+/// once it returns `false`.
+pub(crate) fn register_while<Arg, Func>(callbacks: &LazyCallbacks<Arg>, callback: Func) -> usize
+where
+    Arg: 'static,
+    Func: Fn(Arg) -> bool + Send + 'static,
+{
+    callbacks.shared().modify(|locked| {
+        let id = locked.next_id;
+        locked.next_id += 1;
+        locked
+            .callbacks
+            .push((id, Arc::new(Mutex::new(Box::new(callback)))));
+        id
+    })
+}
+
+/// Calls every registered callback with the given argument, deregistering any that return `false`.
+///
+/// The callbacks are called from a snapshot of the list, without holding the lock on it. So a
+/// callback may register or deregister callbacks, and one that panics doesn't poison the list.
+/// Callbacks registered during the calls aren't called until the next change.
+///
+/// Returns without acquiring any lock if no callbacks are registered.
+pub(crate) fn notify<Arg>(callbacks: &LazyCallbacks<Arg>, arg: Arg)
+where
+    Arg: Clone,
+{
+    let Some(callbacks) = callbacks.get() else {
+        return;
+    };
+    if callbacks.len.load(Ordering::Acquire) == 0 {
+        return;
+    }
+
+    let snapshot = lock(&callbacks.callbacks).callbacks.clone();
+
+    let finished: Vec<usize> = snapshot
+        .into_iter()
+        .filter(|(_, callback)| !(*lock(callback))(arg.clone()))
+        .map(|(id, _)| id)
+        .collect();
+
+    if !finished.is_empty() {
+        callbacks.modify(|locked| locked.callbacks.retain(|(id, _)| !finished.contains(id)));
+    }
+}
+
+/// Deregisters the callback it was created for when dropped.
+///
+/// Created by [`Da::on_set()`] or [`Oda::on_set()`].
+#[must_use = "The callback is deregistered as soon as the handle is dropped"]
+pub struct CallbackHandle {
+    deregister: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl Drop for CallbackHandle {
+    fn drop(&mut self) {
+        if let Some(deregister) = self.deregister.take() {
+            deregister();
+        }
+    }
+}
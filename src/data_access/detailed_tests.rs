@@ -46,7 +46,7 @@ mod test_for_race_condition {
             }
         }
 
-        pub fn push<Func: FnOnce() -> ()>(&self, new_value: Value, func: Func) {
+        pub fn push<Func: FnOnce()>(&self, new_value: Value, func: Func) {
             // If the array is full then allocate a new one
             if *self.len.get() >= *self.capacity.get() {
                 self.array.mutate(|array| {
@@ -80,7 +80,7 @@ mod test_for_race_condition {
             });
         }
 
-        pub fn remove<Func: FnOnce() -> ()>(&self, index: usize, func: Func) -> Option<Arc<Value>> {
+        pub fn remove<Func: FnOnce()>(&self, index: usize, func: Func) -> Option<Arc<Value>> {
             let mut removed = None;
 
             self.array.mutate(|mut array| {
@@ -207,7 +207,7 @@ mod no_race_condition {
             }
         }
 
-        pub fn push<Func: FnOnce() -> ()>(&self, new_value: Value, func: Func) {
+        pub fn push<Func: FnOnce()>(&self, new_value: Value, func: Func) {
             let closure = |mut len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
                 if len >= capacity {
                     // Creates clones of every existing value.
@@ -243,7 +243,7 @@ mod no_race_condition {
                 array; closure);
         }
 
-        pub fn remove<Func: FnOnce() -> ()>(&self, index: usize, func: Func) -> Option<Arc<Value>> {
+        pub fn remove<Func: FnOnce()>(&self, index: usize, func: Func) -> Option<Arc<Value>> {
             let mut removed = None;
 
             let closure = |mut len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
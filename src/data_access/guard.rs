@@ -0,0 +1,114 @@
+//! Contains RAII guards which hold the lock on the underlying data of a [`Da`] or [`Oda`], and
+//! [`DaIndexed`], which holds a reference to the underlying data without locking it.
+
+// Used in docs
+#[allow(unused_imports)]
+use crate::data_access::{Da, Oda};
+
+use crate::sync::{Arc, MutexGuard};
+use core::{
+    fmt::Debug,
+    ops::{Deref, Index},
+};
+
+/// Provides read access to the underlying data of a [`Da`], whilst holding the lock on it.
+///
+/// Created by [`Da::lock_guard()`]. The lock is released when the guard is dropped.
+///
+/// Unlike [`Da::get()`], no other thread can modify the underlying data whilst this guard exists.
+/// Mutation isn't possible through this guard, as it must go through methods such as
+/// [`Da::set()`] to preserve the behaviour of existing references.
+pub struct DaGuard<'a, Value>
+where
+    Value: 'static,
+{
+    pub(super) guard: MutexGuard<'a, Arc<Value>>,
+}
+
+impl<Value> Deref for DaGuard<'_, Value>
+where
+    Value: 'static,
+{
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<Value> Debug for DaGuard<'_, Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("DaGuard").field(&**self).finish()
+    }
+}
+
+/// Provides read access to the underlying data of an [`Oda`], whilst holding the lock on it.
+///
+/// Created by [`Oda::lock_guard()`]. See [`DaGuard`] for more information.
+pub struct OdaGuard<'a, Value>
+where
+    Value: 'static,
+{
+    pub(super) guard: MutexGuard<'a, Option<Arc<Value>>>,
+}
+
+impl<Value> Deref for OdaGuard<'_, Value>
+where
+    Value: 'static,
+{
+    type Target = Option<Arc<Value>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<Value> Debug for OdaGuard<'_, Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OdaGuard").field(&**self).finish()
+    }
+}
+
+/// Provides read access to an element of the underlying data of a [`Da`], such as an item of a
+/// [`Vec`].
+///
+/// Created by [`Da::index_guard()`]. [`Index`] can't be implemented for [`Da`], as it must return
+/// a reference which outlives the lock, allowing the data to be replaced whilst it's borrowed.
+/// Instead this holds a reference to the data at the time it was created, like [`Da::get()`], so
+/// the lock isn't held & the element is unaffected by later changes.
+pub struct DaIndexed<Value, Idx>
+where
+    Value: Index<Idx> + 'static,
+{
+    pub(super) data: Arc<Value>,
+    pub(super) index: Idx,
+}
+
+impl<Value, Idx> Deref for DaIndexed<Value, Idx>
+where
+    Value: Index<Idx> + 'static,
+    Idx: Clone,
+{
+    type Target = Value::Output;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data[self.index.clone()]
+    }
+}
+
+impl<Value, Idx> Debug for DaIndexed<Value, Idx>
+where
+    Value: Index<Idx> + 'static,
+    Value::Output: Debug,
+    Idx: Clone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("DaIndexed").field(&&**self).finish()
+    }
+}
@@ -0,0 +1,227 @@
+//! Contains [`DaHistory`], a [`Da`] which keeps its previous values.
+
+use crate::data_access::{acquire_lock, Da};
+use crate::sync::Arc;
+use alloc::{collections::VecDeque, vec::Vec};
+
+/// A [`Da`] which keeps up to the given amount of its previous values, allowing it to be rolled
+/// back to one of them.
+///
+/// Created by [`DaHistory::new()`] or [`Da::history()`]. Clones share the same value & history.
+pub struct DaHistory<Value>
+where
+    Value: 'static,
+{
+    /// The current value.
+    current: Da<Value>,
+    /// The previous values, from oldest to newest.
+    history: Da<VecDeque<Arc<Value>>>,
+    /// The maximum amount of previous values kept.
+    capacity: usize,
+}
+
+impl<Value> DaHistory<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`DaHistory`] with the given value, which keeps up to the given amount of
+    /// previous values.
+    pub fn new(data: Value, capacity: usize) -> Self {
+        Self::new_from(Da::new(data), capacity)
+    }
+
+    /// Creates a new [`DaHistory`] with the given [`Da`] as the current value.
+    pub(super) fn new_from(current: Da<Value>, capacity: usize) -> Self {
+        Self {
+            current,
+            history: Da::new(VecDeque::new()),
+            capacity,
+        }
+    }
+
+    /// Gets a reference to the current value.
+    ///
+    /// See [`Da::get()`] for more information.
+    pub fn get(&self) -> Arc<Value> {
+        self.current.get()
+    }
+
+    /// Returns the previous values, from oldest to newest.
+    ///
+    /// The current value isn't included.
+    pub fn get_history(&self) -> Vec<Arc<Value>> {
+        acquire_lock(&self.history.current_ref)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the maximum amount of previous values kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the current value, moving the old value into the history; Returning the old value.
+    ///
+    /// If the history is full, the oldest value is discarded.
+    pub fn set(&self, new_data: Value) -> Arc<Value> {
+        self.update(|_| new_data)
+    }
+
+    /// Clones the current value & calls the given function with the clone as the parameter. The
+    /// value returned from the function will be set as the current value, moving the old value
+    /// into the history.
+    ///
+    /// Unlike [`Da::mutate()`], the locks are held whilst the function is executing, so no other
+    /// change can be made in-between. So the function **must not** access this [`DaHistory`].
+    pub fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        self.update(|current| func(current.clone()));
+    }
+
+    /// Restores the value from the given amount of changes ago, discarding the values in-between.
+    ///
+    /// Returns `false` without making any changes if there are fewer previous values than the given
+    /// amount of steps.
+    pub fn rollback(&self, steps: usize) -> bool {
+        if steps == 0 {
+            return true;
+        }
+
+        let mut history = acquire_lock(&self.history.current_ref);
+        let current = acquire_lock(&self.current.current_ref);
+
+        if steps > history.len() {
+            return false;
+        }
+
+        let restored = history.len() - steps;
+        let restored_data = history[restored].clone();
+        Arc::make_mut(&mut history).truncate(restored);
+        drop(history);
+
+        self.current.replace_and_notify(current, restored_data);
+        true
+    }
+
+    /// Replaces the current value with the result of the given function, moving the old value into
+    /// the history; Returning the old value.
+    ///
+    /// The history is locked before the current value, in the same order as [`Self::rollback()`].
+    /// The callbacks registered on the current value are called once both locks are released.
+    fn update<Func>(&self, func: Func) -> Arc<Value>
+    where
+        Func: FnOnce(&Value) -> Value,
+    {
+        let mut history = acquire_lock(&self.history.current_ref);
+        let current = acquire_lock(&self.current.current_ref);
+
+        let new_data = Arc::new(func(&current));
+        let old_data = Arc::clone(&current);
+
+        if self.capacity > 0 {
+            let history = Arc::make_mut(&mut history);
+            if history.len() >= self.capacity {
+                history.pop_front();
+            }
+            history.push_back(old_data.clone());
+        }
+        drop(history);
+
+        self.current.replace_and_notify(current, new_data)
+    }
+}
+
+impl<Value> Clone for DaHistory<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`DaHistory`] pointing to the **exact same** value & history as the original.
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current.clone(),
+            history: self.history.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::Data;
+
+    /// Returns the inner values of the history.
+    fn history(da_history: &DaHistory<Data>) -> Vec<Data> {
+        da_history
+            .get_history()
+            .into_iter()
+            .map(|value| (*value).clone())
+            .collect()
+    }
+
+    #[test]
+    fn keeps_previous_values() {
+        let da_history = DaHistory::new(Data::new(0), 3);
+
+        assert_eq!(*da_history.set(Data::new(1)), 0.into());
+        da_history.mutate(|value| value + 1);
+        assert_eq!(history(&da_history), [0, 1].map(Data::new));
+
+        // The oldest value is discarded once full.
+        da_history.set(Data::new(3));
+        da_history.set(Data::new(4));
+        assert_eq!(history(&da_history), [1, 2, 3].map(Data::new));
+        assert_eq!(*da_history.get(), 4.into());
+    }
+
+    #[test]
+    fn rollback() {
+        let da_history = DaHistory::new(Data::new(0), 5);
+        for num in 1..=4 {
+            da_history.set(Data::new(num));
+        }
+
+        assert!(da_history.rollback(2));
+        assert_eq!(*da_history.get(), 2.into());
+        assert_eq!(history(&da_history), [0, 1].map(Data::new));
+
+        assert!(!da_history.rollback(3));
+        assert_eq!(*da_history.get(), 2.into());
+
+        assert!(da_history.rollback(0));
+        assert!(da_history.rollback(2));
+        assert_eq!(*da_history.get(), 0.into());
+        assert!(da_history.get_history().is_empty());
+    }
+
+    #[test]
+    /// Changes made through the history must call the callbacks of the current value.
+    fn calls_callbacks() {
+        let da = Da::new(Data::new(0));
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let _handle = da.on_set(move |value| sender.send((*value).clone()).unwrap());
+
+        let da_history = da.history(3);
+        da_history.set(Data::new(1));
+        da_history.mutate(|value| value + 1);
+        assert!(da_history.rollback(2));
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<_>>(),
+            [1, 2, 0].map(Data::new)[..]
+        );
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let da_history = DaHistory::new(Data::new(0), 0);
+        da_history.set(Data::new(1));
+
+        assert!(da_history.get_history().is_empty());
+        assert!(!da_history.rollback(1));
+    }
+}
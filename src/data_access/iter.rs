@@ -0,0 +1,103 @@
+//! Contains iterators which yield the underlying data of a [`Da`] or [`Oda`] once.
+
+use crate::{
+    data_access::{Da, Oda},
+    sync::Arc,
+};
+use core::iter::FusedIterator;
+
+/// Yields the underlying data of a [`Da`] exactly once.
+///
+/// Created by [`Da::into_iter_once()`]. The data is read when [`next`](Iterator::next()) is first
+/// called, rather than when the iterator is created.
+pub struct DaOnceIter<Value>
+where
+    Value: 'static,
+{
+    pub(super) data_access: Option<Da<Value>>,
+}
+
+impl<Value> Iterator for DaOnceIter<Value>
+where
+    Value: 'static,
+{
+    type Item = Arc<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data_access.take().map(|data_access| data_access.get())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.data_access.is_some());
+        (len, Some(len))
+    }
+}
+
+impl<Value> ExactSizeIterator for DaOnceIter<Value> where Value: 'static {}
+
+impl<Value> FusedIterator for DaOnceIter<Value> where Value: 'static {}
+
+/// Yields the underlying data of an [`Oda`] once, or nothing if it has no data.
+///
+/// Created by [`Oda::into_iter_once()`]. See [`DaOnceIter`] for when the data is read.
+pub struct OdaOnceIter<Value>
+where
+    Value: 'static,
+{
+    pub(super) data_access: Option<Oda<Value>>,
+}
+
+impl<Value> Iterator for OdaOnceIter<Value>
+where
+    Value: 'static,
+{
+    type Item = Arc<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data_access
+            .take()
+            .and_then(|data_access| data_access.get())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(usize::from(self.data_access.is_some())))
+    }
+}
+
+impl<Value> FusedIterator for OdaOnceIter<Value> where Value: 'static {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn da_once() {
+        let da = Da::new(1);
+        let mut iter = da.clone().into_iter_once();
+        assert_eq!(iter.len(), 1);
+
+        // The data is read lazily.
+        da.set(2);
+        assert_eq!(iter.next().as_deref(), Some(&2));
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn oda_once() {
+        let oda = Oda::new(1);
+        let values: Vec<_> = oda
+            .clone()
+            .into_iter_once()
+            .chain(Oda::default().into_iter_once())
+            .chain(Da::new(2).into_iter_once())
+            .map(|value| *value)
+            .collect();
+        assert_eq!(values, [1, 2]);
+
+        oda.take();
+        assert!(oda.into_iter_once().next().is_none());
+    }
+}
@@ -0,0 +1,427 @@
+//! Contains lazily initialized variants of [`Da`] & [`Oda`].
+//!
+//! The value of these structs is computed by the given initializer on first access, rather than
+//! at construction.
+
+// Used in docs
+#[allow(unused_imports)]
+use crate::data_access::{Da, Oda};
+
+use crate::sync::{self, Arc, Mutex, MutexGuard, PoisonError};
+use alloc::boxed::Box;
+
+/// The message used when accessing a value whose initializer panicked.
+const INIT_PANICKED_MESSAGE: &str = "The initializer panicked whilst computing the value";
+
+/// Acquires the lock on the given [`Mutex`], ignoring any poison.
+///
+/// A panic within the initializer is recorded by the `Poisoned` state instead, so the lock being
+/// poisoned doesn't need to be handled separately.
+fn lock_state<State>(mutex: &Mutex<State>) -> MutexGuard<'_, State> {
+    sync::lock(mutex).unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The initialization state of a [`LazyDa`].
+pub enum LazyDaState<Value> {
+    /// The value hasn't been accessed yet, so only the initializer is held.
+    Uninitialized(Box<dyn FnOnce() -> Value + Send>),
+    /// The value has been computed.
+    Initialized(Arc<Value>),
+    /// The initializer panicked, so there is no value.
+    Poisoned,
+}
+
+impl<Value> LazyDaState<Value> {
+    /// Calls the initializer if it hasn't been called yet, returning the initialized value.
+    ///
+    /// # Panics
+    /// If the initializer panics, either during this call or a previous one.
+    fn force(&mut self) -> &mut Arc<Value> {
+        if let Self::Uninitialized(_) = self {
+            // The state is poisoned whilst the initializer runs, so remains so if it panics.
+            let Self::Uninitialized(init) = core::mem::replace(self, Self::Poisoned) else {
+                unreachable!("Checked above")
+            };
+            *self = Self::Initialized(Arc::new(init()));
+        }
+
+        match self {
+            Self::Initialized(value) => value,
+            Self::Poisoned => panic!("{INIT_PANICKED_MESSAGE}"),
+            Self::Uninitialized(_) => unreachable!("Initialized above"),
+        }
+    }
+}
+
+/// The initialization state of a [`LazyOda`].
+pub enum LazyOdaState<Value> {
+    /// The value hasn't been accessed yet, so only the initializer is held.
+    Uninitialized(Box<dyn FnOnce() -> Option<Value> + Send>),
+    /// The value has been computed.
+    Initialized(Option<Arc<Value>>),
+    /// The initializer panicked, so there is no value.
+    Poisoned,
+}
+
+impl<Value> LazyOdaState<Value> {
+    /// Calls the initializer if it hasn't been called yet, returning the initialized value.
+    ///
+    /// # Panics
+    /// If the initializer panics, either during this call or a previous one.
+    fn force(&mut self) -> &mut Option<Arc<Value>> {
+        if let Self::Uninitialized(_) = self {
+            // The state is poisoned whilst the initializer runs, so remains so if it panics.
+            let Self::Uninitialized(init) = core::mem::replace(self, Self::Poisoned) else {
+                unreachable!("Checked above")
+            };
+            *self = Self::Initialized(init().map(Arc::new));
+        }
+
+        match self {
+            Self::Initialized(value) => value,
+            Self::Poisoned => panic!("{INIT_PANICKED_MESSAGE}"),
+            Self::Uninitialized(_) => unreachable!("Initialized above"),
+        }
+    }
+}
+
+/// [`LazyDataAccess`](LazyDa)
+/// ---
+///
+/// A [`Da`] whose value is computed by the given initializer on first access.
+///
+/// The initializer is called **exactly once**, even if multiple threads access the value
+/// concurrently. Any other threads will wait for the initializer to finish.
+///
+/// If the initializer panics, every subsequent access panics too, until a value is
+/// [`set`](Self::set()).
+pub struct LazyDa<Value>
+where
+    Value: 'static,
+{
+    /// Contains the initialization state of the data being represented.
+    ///
+    /// See [`Da`] for the reasoning behind the layout.
+    current_ref: Arc<Mutex<LazyDaState<Value>>>,
+}
+
+impl<Value> LazyDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`LazyDa<Value>`], which will call the given initializer on first access.
+    ///
+    /// See [`Da::from_fn()`] to call the initializer immediately.
+    #[doc(alias = "new_with")]
+    pub fn new<Func>(init: Func) -> Self
+    where
+        Func: FnOnce() -> Value + Send + 'static,
+    {
+        Self {
+            current_ref: Arc::new(Mutex::new(LazyDaState::Uninitialized(Box::new(init)))),
+        }
+    }
+
+    /// Returns `true` if the value has been initialized.
+    pub fn is_initialized(&self) -> bool {
+        matches!(*lock_state(&self.current_ref), LazyDaState::Initialized(_))
+    }
+
+    /// Gets a reference to the current underlying data, initializing it if required.
+    ///
+    /// See [`Da::get()`] for more information.
+    pub fn get(&self) -> Arc<Value> {
+        lock_state(&self.current_ref).force().clone()
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value.
+    ///
+    /// If the value hasn't been initialized yet, the initializer is discarded without being called
+    /// & `None` is returned. This also replaces the value of an initializer that panicked.
+    ///
+    /// See [`Da::set()`] for more information on the behaviour of current & future references.
+    pub fn set(&self, new_data: Value) -> Option<Arc<Value>> {
+        let mut state = lock_state(&self.current_ref);
+        match core::mem::replace(&mut *state, LazyDaState::Initialized(Arc::new(new_data))) {
+            LazyDaState::Uninitialized(_) | LazyDaState::Poisoned => None,
+            LazyDaState::Initialized(old_data) => Some(old_data),
+        }
+    }
+
+    /// Clones the existing underlying data, initializing it if required, & calls the given function
+    /// with the clone as the parameter. The value returned from the function will be set as the new
+    /// underlying data.
+    ///
+    /// See [`Da::mutate()`] for more information, including the locking behaviour.
+    pub fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        let mutated_value = func((*self.get()).clone());
+        self.set(mutated_value);
+    }
+}
+
+impl<Value> Clone for LazyDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`LazyDa`] pointing to the **exact same** value as the original [`LazyDa`].
+    fn clone(&self) -> Self {
+        Self {
+            current_ref: self.current_ref.clone(),
+        }
+    }
+}
+
+/// [`LazyOptionalDataAccess`](LazyOda)
+/// ---
+///
+/// An [`Oda`] whose value is computed by the given initializer on first access.
+///
+/// See [`LazyDa`] for more information on the initialization behaviour.
+pub struct LazyOda<Value>
+where
+    Value: 'static,
+{
+    /// Contains the initialization state of the data being represented.
+    ///
+    /// See [`Oda`] for the reasoning behind the layout.
+    current_ref: Arc<Mutex<LazyOdaState<Value>>>,
+}
+
+impl<Value> LazyOda<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`LazyOda<Value>`], which will call the given initializer on first access.
+    pub fn new<Func>(init: Func) -> Self
+    where
+        Func: FnOnce() -> Option<Value> + Send + 'static,
+    {
+        Self {
+            current_ref: Arc::new(Mutex::new(LazyOdaState::Uninitialized(Box::new(init)))),
+        }
+    }
+
+    /// Returns `true` if the value has been initialized.
+    pub fn is_initialized(&self) -> bool {
+        matches!(*lock_state(&self.current_ref), LazyOdaState::Initialized(_))
+    }
+
+    /// Gets a reference to the current underlying data, initializing it if required.
+    ///
+    /// See [`Oda::get()`] for more information.
+    pub fn get(&self) -> Option<Arc<Value>> {
+        lock_state(&self.current_ref).force().clone()
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value.
+    ///
+    /// If the value hasn't been initialized yet, the initializer is discarded without being called
+    /// & `None` is returned. This also replaces the value of an initializer that panicked.
+    ///
+    /// See [`Oda::set()`] for more information on the behaviour of current & future references.
+    pub fn set(&self, new_data: Value) -> Option<Arc<Value>> {
+        let mut state = lock_state(&self.current_ref);
+        match core::mem::replace(
+            &mut *state,
+            LazyOdaState::Initialized(Some(Arc::new(new_data))),
+        ) {
+            LazyOdaState::Uninitialized(_) | LazyOdaState::Poisoned => None,
+            LazyOdaState::Initialized(old_data) => old_data,
+        }
+    }
+
+    /// Takes the value out of the [`LazyOda`], initializing it if required & leaving `None` in its place.
+    pub fn take(&self) -> Option<Arc<Value>> {
+        lock_state(&self.current_ref).force().take()
+    }
+
+    /// If there is underlying data, initializing it if required, it's cloned & the given function
+    /// will be called with it as the parameter. The value returned from the function will be set as
+    /// the new underlying data.
+    ///
+    /// See [`Oda::mutate()`] for more information, including the locking behaviour.
+    pub fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        if let Some(old_value) = self.get() {
+            let mutated_value = func((*old_value).clone());
+            self.set(mutated_value);
+        }
+    }
+}
+
+impl<Value> Clone for LazyOda<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`LazyOda`] pointing to the **exact same** value as the original [`LazyOda`].
+    fn clone(&self) -> Self {
+        Self {
+            current_ref: self.current_ref.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        string::String,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Barrier,
+        },
+        thread::{self, sleep},
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::test_data::Data;
+
+    #[test]
+    fn initializes_on_access() {
+        let lazy_da = LazyDa::new(|| Data::new(5));
+        assert!(!lazy_da.is_initialized());
+
+        assert_eq!(*lazy_da.get(), 5.into());
+        assert!(lazy_da.is_initialized());
+    }
+
+    #[test]
+    /// The initializer must only be called once, even when multiple threads race to access the value.
+    fn initializes_once() {
+        const THREADS: usize = 8;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let calls_clone = calls.clone();
+        let lazy_da = LazyDa::new(move || {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            // Widens the window for other threads to race the initialization.
+            sleep(Duration::from_millis(50));
+            Data::new(5)
+        });
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let lazy_da = lazy_da.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    lazy_da.get()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(*handle.join().unwrap(), 5.into());
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    /// Setting the value before access discards the initializer.
+    fn set_before_initialized() {
+        let lazy_da = LazyDa::new(|| -> Data { panic!("The initializer mustn't be called") });
+
+        assert!(lazy_da.set(Data::new(1)).is_none());
+        assert_eq!(*lazy_da.get(), 1.into());
+    }
+
+    #[test]
+    fn set_and_mutate() {
+        let lazy_da = LazyDa::new(|| Data::new(1));
+        let initial = lazy_da.get();
+
+        assert_eq!(*lazy_da.set(Data::new(2)).unwrap(), 1.into());
+        lazy_da.mutate(|value| value + 1);
+
+        assert_eq!(*lazy_da.get(), 3.into());
+        // Old references remain valid.
+        assert_eq!(*initial, 1.into());
+    }
+
+    #[test]
+    /// Accessing the value after the initializer panicked must report that, rather than calling
+    /// the initializer again.
+    fn initializer_panics() {
+        let lazy_da = LazyDa::new(|| -> Data { panic!("Initializer failed") });
+
+        let first = catch_unwind(AssertUnwindSafe(|| lazy_da.get())).unwrap_err();
+        assert_eq!(first.downcast_ref::<&str>(), Some(&"Initializer failed"));
+
+        let second = catch_unwind(AssertUnwindSafe(|| lazy_da.get())).unwrap_err();
+        assert_eq!(
+            second.downcast_ref::<String>().map(String::as_str),
+            Some(INIT_PANICKED_MESSAGE)
+        );
+        assert!(!lazy_da.is_initialized());
+
+        // Setting a value recovers from the panic.
+        assert!(lazy_da.set(Data::new(1)).is_none());
+        assert_eq!(*lazy_da.get(), 1.into());
+
+        let lazy_oda = LazyOda::new(|| -> Option<Data> { panic!("Initializer failed") });
+        assert!(catch_unwind(AssertUnwindSafe(|| lazy_oda.get())).is_err());
+        let second = catch_unwind(AssertUnwindSafe(|| lazy_oda.take())).unwrap_err();
+        assert_eq!(
+            second.downcast_ref::<String>().map(String::as_str),
+            Some(INIT_PANICKED_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn optional_initializes_once() {
+        const THREADS: usize = 8;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let calls_clone = calls.clone();
+        let lazy_oda = LazyOda::new(move || {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            sleep(Duration::from_millis(50));
+            Some(Data::new(5))
+        });
+        assert!(!lazy_oda.is_initialized());
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let lazy_oda = lazy_oda.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    lazy_oda.get()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(*handle.join().unwrap().unwrap(), 5.into());
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(lazy_oda.is_initialized());
+    }
+
+    #[test]
+    fn optional_set_mutate_and_take() {
+        let lazy_oda: LazyOda<Data> = LazyOda::new(|| None);
+        assert!(lazy_oda.get().is_none());
+
+        // Mutating no data has no effect.
+        lazy_oda.mutate(|value| value + 1);
+        assert!(lazy_oda.get().is_none());
+
+        assert!(lazy_oda.set(Data::new(1)).is_none());
+        lazy_oda.mutate(|value| value + 1);
+        assert_eq!(*lazy_oda.take().unwrap(), 2.into());
+        assert!(lazy_oda.get().is_none());
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
     locking_mutate as lm,
 };
 
-use std::sync::Arc;
+use crate::sync::Arc;
 
 /// Used to convert between types, see module comments.
 pub struct Converter<Value>(Option<Arc<Value>>);
@@ -1,5 +1,6 @@
-//! Contains the [`locking_mutate`] macro.
-//! See its documentation for more detail.
+//! Contains the [`locking_mutate!`](crate::locking_mutate!) macro & its read-only counterpart
+//! [`locking_get!`](crate::locking_get!).
+//! See their documentation for more detail.
 
 pub mod data_structures;
 
@@ -8,13 +9,17 @@ pub use data_structures::*;
 // Used in docs
 #[allow(unused_imports)]
 use crate::data_access::{Da, Oda};
-use std::sync::{Arc, MutexGuard};
+use crate::sync::{Arc, MutexGuard};
 
 #[macro_export]
 /**
 Holds the lock on the internal data for [`Da`] or [`Oda`] structs whilst executing the given
 closure on the internal values.
 
+The locks are acquired in the order the values are given, which can deadlock against another
+thread giving the same values in a different order. See [Deadlocks](#deadlocks), or use
+[`safe_locking_mutate!`](crate::safe_locking_mutate!) to acquire them in a consistent order.
+
 # Examples
 
 ```
@@ -72,8 +77,53 @@ closure on the internal values.
    // Any values captured by the closure can still be safely used after.
    assert_eq!(name, "AXE");
 ```
+
+When only a single Da or Oda is given, the closure takes & returns the value directly, rather
+than as a tuple.
+
+```
+   use cell_memory::{data_access::Da, locking_mutate};
+
+   let score = Da::new(5);
+   locking_mutate!(score; |score: u64| score + 1);
+
+   assert_eq!(score.copy_value(), 6);
+```
+
+# Panics
+
+If the same data is given more than once, as its lock would otherwise be acquired twice,
+deadlocking the thread. Giving the same variable twice already fails to compile, but clones of
+the same Da or Oda can only be detected at runtime.
+
+# Deadlocks
+
+The locks are acquired in the order the Da or Oda are given. So if another thread calls this macro
+with the same values in a different order, both threads can deadlock. Either always give the
+values in the same order, or use [`safe_locking_mutate!`](crate::safe_locking_mutate!) instead.
+
+The given order is kept, rather than sorting by address, so that the locks can be acquired in the
+same order as code which locks the values individually, such as [`CellVec`] locking its length
+before its array.
+
+[`CellVec`]: crate::vec::CellVec
 */
 macro_rules! locking_mutate {
+    // A single value is passed into & returned from the closure directly, rather than as a tuple.
+    ($data_access:ident; $func:expr) => {
+        {
+            // See the multiple value case below for the reasoning behind this import.
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            let mut lock = $crate::data_access::locking_mutate::Lock::lock(&$data_access);
+            let modified = $func(lock.ooa());
+
+            *lock = {
+                let value = Wrapper::from(modified);
+                value.into()
+            };
+        }
+    };
     ($($data_access:ident), +; $func:expr) => {
         {
             // This macro allows for creating new identities within rust code.
@@ -89,8 +139,12 @@ macro_rules! locking_mutate {
             // at compile time by this macro.
             use $crate::data_access::locking_mutate::data_structures::*;
 
+            $crate::data_access::locking_mutate::assert_distinct(
+                &[$($crate::data_access::locking_mutate::Lock::lock_address(&$data_access),)+]
+            );
+
             // Assigned each acquired mutex lock to unique local variables.
-            // TODO: resolve possible dead-lock
+            // The locks are acquired in the given order, see the docs for the possible deadlock.
             let ($(paste!{mut [<$data_access _lock>]}, )+) = ($($crate::data_access::locking_mutate::Lock::lock(&$data_access),)+);
 
             // Executes the given function/closure.
@@ -116,6 +170,346 @@ macro_rules! locking_mutate {
     };
 }
 
+#[macro_export]
+/**
+The same as [`locking_mutate!`](crate::locking_mutate!), except that the locks are acquired in
+order of the address of each Da or Oda's underlying data, rather than the order they are given in.
+
+This means that calling this macro with the same values in any order, from any amount of threads,
+won't deadlock. The values are still passed into & returned from the closure in the given order.
+
+Since a declarative macro can't sort at compile time, the addresses are sorted at runtime.
+
+# Panics
+
+If the same data is given more than once, as its lock would otherwise be acquired twice.
+
+# Examples
+
+```
+   use cell_memory::{data_access::{Da, Oda}, safe_locking_mutate};
+   use std::thread;
+
+   let score = Da::new(5u64);
+   let highscore = Oda::new(8u64);
+
+   let handle = {
+       let (score, highscore) = (score.clone(), highscore.clone());
+       thread::spawn(move || {
+           safe_locking_mutate!(highscore, score; |highscore: Option<u64>, score: u64| {
+               (highscore.map(|highscore| highscore.max(score)), score)
+           });
+       })
+   };
+
+   // The reverse order can't deadlock with the thread above.
+   safe_locking_mutate!(score, highscore; |score: u64, highscore: Option<u64>| {
+       (score + 5, highscore)
+   });
+   handle.join().unwrap();
+
+   assert_eq!(score.copy_value(), 10);
+```
+*/
+macro_rules! safe_locking_mutate {
+    // A single lock can't be acquired out of order.
+    ($data_access:ident; $func:expr) => {
+        $crate::locking_mutate!($data_access; $func)
+    };
+    ($($data_access:ident), +; $func:expr) => {
+        {
+            use paste::paste;
+            use $crate::data_access::locking_mutate::data_structures::*;
+            use $crate::data_access::locking_mutate::Lock;
+
+            let addresses = [$(Lock::lock_address(&$data_access),)+];
+            $crate::data_access::locking_mutate::assert_distinct(&addresses);
+            let mut sorted = addresses;
+            sorted.sort_unstable();
+
+            // The position of each lock in the acquisition order.
+            let [$(paste!{[<$data_access _rank>]},)+] =
+                addresses.map(|address| sorted.partition_point(|other| *other < address));
+
+            $(paste! { let mut [<$data_access _lock>] = None; })+
+            for rank in 0..addresses.len() {
+                $(
+                    if paste!{[<$data_access _rank>]} == rank {
+                        paste! { [<$data_access _lock>] = Some(Lock::lock(&$data_access)); }
+                    }
+                )+
+            }
+            $(paste! { let mut [<$data_access _lock>] = [<$data_access _lock>].expect("Every lock is acquired above"); })+
+
+            // See `locking_mutate!` for the reasoning behind the following.
+            let ($(paste!{[<$data_access _modified>]}, )+) = $func($(
+                paste!{[<$data_access _lock>]}.ooa(),
+            )+);
+
+            $(
+                *paste!{[<$data_access _lock>]} = {
+                    let value = paste!{[<$data_access _modified>]};
+                    let value = Wrapper::from(value);
+                    value.into()
+                };
+            )+
+        }
+    };
+}
+
+#[macro_export]
+/**
+The same as [`locking_mutate!`](crate::locking_mutate!), except that the closure returns a
+[`Result`] of the modified values.
+
+On [`Ok`], every value is modified & `Ok(())` is returned. On [`Err`], none of the values are
+modified & the error is returned. The locks are held until the values have been modified, so no
+other thread can observe them part way through.
+
+# Examples
+
+```
+   use cell_memory::{data_access::Da, locking_mutate_try};
+
+   let balance = Da::new(5u64);
+   let spent = Da::new(0u64);
+
+   let closure = |balance: u64, spent: u64| match balance.checked_sub(10) {
+       Some(balance) => Ok((balance, spent + 10)),
+       None => Err("Insufficient balance"),
+   };
+
+   assert_eq!(locking_mutate_try!(balance, spent; closure), Err("Insufficient balance"));
+
+   // Neither value is modified.
+   assert_eq!(balance.copy_value(), 5);
+   assert_eq!(spent.copy_value(), 0);
+```
+
+# Deadlocks
+
+The locks are acquired in the order the Da or Oda are given, in the same way as
+[`locking_mutate!`](crate::locking_mutate!).
+*/
+macro_rules! locking_mutate_try {
+    ($data_access:ident; $func:expr) => {
+        {
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            let mut lock = $crate::data_access::locking_mutate::Lock::lock(&$data_access);
+            match $func(lock.ooa()) {
+                Ok(modified) => {
+                    *lock = {
+                        let value = Wrapper::from(modified);
+                        value.into()
+                    };
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            }
+        }
+    };
+    ($($data_access:ident), +; $func:expr) => {
+        {
+            use paste::paste;
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            $crate::data_access::locking_mutate::assert_distinct(
+                &[$($crate::data_access::locking_mutate::Lock::lock_address(&$data_access),)+]
+            );
+
+            // The locks are acquired in the given order, see `locking_mutate!` for the possible deadlock.
+            let ($(paste!{mut [<$data_access _lock>]}, )+) = ($($crate::data_access::locking_mutate::Lock::lock(&$data_access),)+);
+
+            // The result is checked before any value is modified.
+            match $func($(paste!{[<$data_access _lock>]}.ooa(),)+) {
+                Ok(($(paste!{[<$data_access _modified>]}, )+)) => {
+                    $(
+                        *paste!{[<$data_access _lock>]} = {
+                            let value = paste!{[<$data_access _modified>]};
+                            let value = Wrapper::from(value);
+                            value.into()
+                        };
+                    )+
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            }
+        }
+    };
+}
+
+#[macro_export]
+/**
+The read-only counterpart of [`locking_mutate!`](crate::locking_mutate!). Holds the locks on the
+internal data of every given Da or Oda whilst executing the given closure, returning its result.
+
+The closure is given the same values as with [`locking_mutate!`](crate::locking_mutate!), but
+none of the values are modified. As every lock is held at once, the values are read at the same
+point in time, which allows checking an invariant spanning multiple values.
+
+# Examples
+
+```
+   use cell_memory::{data_access::{Da, Oda}, locking_get, locking_mutate};
+
+   let lives = Da::new(3u8);
+   let deaths = Oda::new(0u8);
+
+   locking_mutate!(lives, deaths; |lives: u8, deaths: Option<u8>| {
+       (lives - 1, deaths.map(|deaths| deaths + 1))
+   });
+
+   // Both values are always changed together, so their sum is consistent.
+   let total = locking_get!(lives, deaths; |lives: u8, deaths: Option<u8>| {
+       lives + deaths.unwrap_or_default()
+   });
+   assert_eq!(total, 3);
+```
+
+# Panics
+
+If the same data is given more than once, see [`locking_mutate!`](crate::locking_mutate!).
+
+# Deadlocks
+
+The locks are acquired in the order the Da or Oda are given, in the same way as
+[`locking_mutate!`](crate::locking_mutate!).
+*/
+macro_rules! locking_get {
+    ($data_access:ident; $func:expr) => {
+        {
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            let lock = $crate::data_access::locking_mutate::Lock::lock(&$data_access);
+            $func(lock.ooa())
+        }
+    };
+    ($($data_access:ident), +; $func:expr) => {
+        {
+            use paste::paste;
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            $crate::data_access::locking_mutate::assert_distinct(
+                &[$($crate::data_access::locking_mutate::Lock::lock_address(&$data_access),)+]
+            );
+
+            // The locks are acquired in the given order, see `locking_mutate!` for the possible deadlock.
+            let ($(paste!{[<$data_access _lock>]}, )+) = ($($crate::data_access::locking_mutate::Lock::lock(&$data_access),)+);
+
+            // The locks are released once the result has been returned.
+            $func($(paste!{[<$data_access _lock>]}.ooa(),)+)
+        }
+    };
+}
+
+#[cfg(feature = "parking_lot")]
+#[macro_export]
+/**
+The same as [`locking_mutate!`](crate::locking_mutate!), except that the closure isn't called if
+every lock couldn't be acquired within the given [`Duration`](std::time::Duration).
+
+Returns `Some(())` if the closure was called, or `None` if the timeout elapsed. Any locks acquired
+before the timeout elapsed are released without modifying their data.
+
+Requires the `parking_lot` feature.
+
+# Examples
+
+```
+   use cell_memory::{data_access::Da, locking_mutate_timeout};
+   use std::time::Duration;
+
+   let score = Da::new(5u64);
+   let lives = Da::new(3u8);
+
+   let result = locking_mutate_timeout!(score, lives; Duration::from_millis(10);
+       |score: u64, lives: u8| (score + 1, lives - 1)
+   );
+
+   assert!(result.is_some());
+   assert_eq!(score.copy_value(), 6);
+   assert_eq!(lives.copy_value(), 2);
+```
+*/
+macro_rules! locking_mutate_timeout {
+    ($data_access:ident; $timeout:expr; $func:expr) => {
+        {
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            match $crate::data_access::locking_mutate::LockTimeout::lock_timeout(&$data_access, $timeout) {
+                Some(mut lock) => {
+                    let modified = $func(lock.ooa());
+                    *lock = {
+                        let value = Wrapper::from(modified);
+                        value.into()
+                    };
+                    Some(())
+                }
+                None => None,
+            }
+        }
+    };
+    ($($data_access:ident), +; $timeout:expr; $func:expr) => {
+        {
+            use paste::paste;
+            use $crate::data_access::locking_mutate::data_structures::*;
+
+            $crate::data_access::locking_mutate::assert_distinct(
+                &[$($crate::data_access::locking_mutate::Lock::lock_address(&$data_access),)+]
+            );
+
+            // The timeout applies to acquiring every lock, rather than each individual lock.
+            // A timeout too large to represent as a deadline waits without one.
+            let deadline = std::time::Instant::now().checked_add($timeout);
+
+            (|| {
+                $(
+                    paste! {
+                        let mut [<$data_access _lock>] = match deadline {
+                            Some(deadline) => $crate::data_access::locking_mutate::LockTimeout::lock_timeout(
+                                &$data_access,
+                                deadline.saturating_duration_since(std::time::Instant::now()),
+                            )?,
+                            None => $crate::data_access::locking_mutate::Lock::lock(&$data_access),
+                        };
+                    }
+                )+
+
+                // See `locking_mutate!` for the reasoning behind the following.
+                let ($(paste!{[<$data_access _modified>]}, )+) = $func($(
+                    paste!{[<$data_access _lock>]}.ooa(),
+                )+);
+
+                $(
+                    *paste!{[<$data_access _lock>]} = {
+                        let value = paste!{[<$data_access _modified>]};
+                        let value = Wrapper::from(value);
+                        value.into()
+                    };
+                )+
+
+                Some(())
+            })()
+        }
+    };
+}
+
+/// Panics if any of the given lock addresses are the same, as acquiring the same lock twice would
+/// deadlock.
+///
+/// Used by the locking macros, so isn't part of the public API.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_distinct(addresses: &[usize]) {
+    for (index, address) in addresses.iter().enumerate() {
+        assert!(
+            !addresses[index + 1..].contains(address),
+            "The same data was given more than once, which would deadlock"
+        );
+    }
+}
+
 /// Provides solitary access to data via a [`MutexGuard`].
 pub trait Lock<Value> {
     /// The value contained within the returned [`MutexGuard`].
@@ -123,6 +517,30 @@ pub trait Lock<Value> {
 
     /// Returns a [`MutexGuard`] to the underlying data represented by this struct.
     fn lock(&self) -> MutexGuard<'_, Self::Returns>;
+
+    /// Returns the address of the lock on the underlying data, which is shared between clones.
+    ///
+    /// Used by [`safe_locking_mutate!`](crate::safe_locking_mutate!) to order the locks, and by
+    /// every locking macro to detect the same data being given more than once.
+    ///
+    /// By default, the lock is briefly acquired to read the address of the data it guards, which
+    /// is the same for every guard of the same lock. [`Da`] & [`Oda`] read the address without
+    /// acquiring the lock.
+    fn lock_address(&self) -> usize {
+        let guard = self.lock();
+        &*guard as *const Self::Returns as usize
+    }
+}
+
+/// Provides solitary access to data via a [`MutexGuard`], giving up after a timeout.
+///
+/// Separate from [`Lock`], so that enabling the `parking_lot` feature doesn't add a method that
+/// other implementors of [`Lock`] would have to provide.
+#[cfg(feature = "parking_lot")]
+pub trait LockTimeout<Value>: Lock<Value> {
+    /// Returns a [`MutexGuard`] to the underlying data represented by this struct, or `None` if
+    /// the lock couldn't be acquired within the given timeout.
+    fn lock_timeout(&self, timeout: std::time::Duration) -> Option<MutexGuard<'_, Self::Returns>>;
 }
 
 impl<Value> Lock<Value> for crate::data_access::Oda<Value>
@@ -132,7 +550,21 @@ where
     type Returns = Option<Arc<Value>>;
 
     fn lock(&self) -> MutexGuard<'_, Self::Returns> {
-        self.current_ref.lock().unwrap()
+        crate::data_access::acquire_lock(&self.current_ref)
+    }
+
+    fn lock_address(&self) -> usize {
+        self.lock_address()
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+impl<Value> LockTimeout<Value> for crate::data_access::Oda<Value>
+where
+    Value: 'static,
+{
+    fn lock_timeout(&self, timeout: std::time::Duration) -> Option<MutexGuard<'_, Self::Returns>> {
+        crate::sync::lock_timeout(&self.current_ref, timeout)
     }
 }
 
@@ -143,12 +575,28 @@ where
     type Returns = Arc<Value>;
 
     fn lock(&self) -> MutexGuard<'_, Self::Returns> {
-        self.current_ref.lock().unwrap()
+        crate::data_access::acquire_lock(&self.current_ref)
+    }
+
+    fn lock_address(&self) -> usize {
+        self.lock_address()
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+impl<Value> LockTimeout<Value> for crate::data_access::Da<Value>
+where
+    Value: 'static,
+{
+    fn lock_timeout(&self, timeout: std::time::Duration) -> Option<MutexGuard<'_, Self::Returns>> {
+        crate::sync::lock_timeout(&self.current_ref, timeout)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::thread;
+
     use crate::{
         data_access::{Da, Oda},
         test_data::Data,
@@ -167,6 +615,19 @@ mod tests {
         assert_eq!(*da_two.get(), 3.into());
     }
 
+    #[test]
+    /// A single value must be passed & returned without a tuple.
+    fn single_lock() {
+        let da = Da::new(Data::new(1));
+        locking_mutate!(da; |value: Data| value + 1);
+        assert_eq!(*da.get(), 2.into());
+
+        let oda = Oda::new(Data::new(1));
+        let closure = |value: Option<Data>| value.map(|value| value + 1);
+        locking_mutate!(oda; closure);
+        assert_eq!(*oda.get().unwrap(), 2.into());
+    }
+
     #[test]
     fn oda_lock() {
         let oda_one = Oda::new(Data::new(1));
@@ -179,4 +640,193 @@ mod tests {
         assert_eq!(*oda_one.get().unwrap(), 2.into());
         assert_eq!(*oda_two.get().unwrap(), 3.into());
     }
+
+    #[test]
+    /// The values must be passed & returned in the given order, regardless of the lock order.
+    fn safe_lock() {
+        let da = Da::new(Data::new(1));
+        let oda = Oda::new(Data::new(10));
+
+        safe_locking_mutate!(oda, da; |oda: Option<Data>, da: Data| (oda.map(|value| value + 1), da + 2));
+        safe_locking_mutate!(da, oda; |da: Data, oda: Option<Data>| (da + 1, oda.map(|value| value + 2)));
+        safe_locking_mutate!(da; |da: Data| da + 1);
+
+        assert_eq!(*da.get(), 5.into());
+        assert_eq!(*oda.get().unwrap(), 13.into());
+    }
+
+    #[test]
+    /// Locking in opposite orders from different threads mustn't deadlock.
+    fn safe_lock_opposite_order() {
+        let da_one = Da::new(0);
+        let da_two = Da::new(0);
+
+        let handles: Vec<_> = (0..2)
+            .map(|thread| {
+                let (da_one, da_two) = (da_one.clone(), da_two.clone());
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        if thread == 0 {
+                            safe_locking_mutate!(da_one, da_two; |one: i32, two: i32| (one + 1, two + 1));
+                        } else {
+                            safe_locking_mutate!(da_two, da_one; |two: i32, one: i32| (two + 1, one + 1));
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(da_one.copy_value(), 2000);
+        assert_eq!(da_two.copy_value(), 2000);
+    }
+
+    #[test]
+    #[should_panic(expected = "The same data was given more than once")]
+    fn lock_same_data() {
+        let da = Da::new(0);
+        let oda = Oda::new(0);
+        let da_clone = da.clone();
+        locking_mutate!(da, oda, da_clone; |one: i32, oda: Option<i32>, two: i32| (one, oda, two));
+    }
+
+    #[test]
+    #[should_panic(expected = "The same data was given more than once")]
+    fn try_lock_same_data() {
+        let da = Da::new(0);
+        let da_clone = da.clone();
+        let _ = locking_mutate_try!(da, da_clone; |one: i32, two: i32| Ok::<_, ()>((one, two)));
+    }
+
+    #[test]
+    #[should_panic(expected = "The same data was given more than once")]
+    fn safe_lock_same_data() {
+        let da = Da::new(0);
+        let da_clone = da.clone();
+        safe_locking_mutate!(da, da_clone; |one: i32, two: i32| (one, two));
+    }
+
+    #[test]
+    fn try_lock() {
+        let da = Da::new(Data::new(1));
+        let oda = Oda::new(Data::new(1));
+
+        let closure = |da: Data, oda: Option<Data>| -> Result<_, ()> {
+            Ok((da + 1, oda.map(|value| value + 1)))
+        };
+        assert!(locking_mutate_try!(da, oda; closure).is_ok());
+        assert!(locking_mutate_try!(da; |da: Data| -> Result<_, ()> { Ok(da + 1) }).is_ok());
+
+        // No value is modified on an error.
+        let closure =
+            |_: Data, _: Option<Data>| -> Result<(Data, Option<Data>), &str> { Err("error") };
+        assert_eq!(locking_mutate_try!(da, oda; closure), Err("error"));
+        assert!(locking_mutate_try!(oda; |_: Option<Data>| Err::<Option<Data>, _>(())).is_err());
+
+        assert_eq!(*da.get(), 3.into());
+        assert_eq!(*oda.get().unwrap(), 2.into());
+    }
+
+    #[test]
+    fn get_lock() {
+        let da = Da::new(Data::new(1));
+        let oda = Oda::new(Data::new(2));
+
+        let sum = locking_get!(da, oda; |da: Data, oda: Option<Data>| da + oda.unwrap());
+        assert_eq!(sum, 3.into());
+        assert!(locking_get!(oda; |oda: Option<Data>| oda.is_some()));
+
+        // No value is modified.
+        assert_eq!(*da.get(), 1.into());
+        assert_eq!(*oda.get().unwrap(), 2.into());
+    }
+
+    #[test]
+    /// A reader holding both locks must never see one value updated without the other.
+    fn get_lock_consistent() {
+        let first = Da::new(0);
+        let second = Da::new(0);
+
+        let writer = {
+            let (first, second) = (first.clone(), second.clone());
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    locking_mutate!(first, second; |first: i32, second: i32| (first + 1, second + 1));
+                }
+            })
+        };
+
+        while !writer.is_finished() {
+            let (first, second) =
+                locking_get!(first, second; |first: i32, second: i32| (first, second));
+            assert_eq!(first, second);
+        }
+        writer.join().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "The same data was given more than once")]
+    fn get_lock_same_data() {
+        let da = Da::new(1);
+        let da_clone = da.clone();
+        locking_get!(da, da_clone; |_: i32, _: i32| ());
+    }
+
+    #[test]
+    /// The default address must be shared between handles to the same lock, so that giving the
+    /// same data twice is detected for other implementors too.
+    fn default_lock_address() {
+        use crate::data_access::locking_mutate::Lock;
+        use crate::sync::{Arc, Mutex, MutexGuard};
+
+        struct Handle(Arc<Mutex<i32>>);
+
+        impl Lock<i32> for Handle {
+            type Returns = i32;
+
+            fn lock(&self) -> MutexGuard<'_, i32> {
+                crate::data_access::acquire_lock(&self.0)
+            }
+        }
+
+        let shared = Arc::new(Mutex::new(0));
+        let (first, second) = (Handle(shared.clone()), Handle(shared));
+        let other = Handle(Arc::new(Mutex::new(0)));
+
+        assert_eq!(first.lock_address(), second.lock_address());
+        assert_ne!(first.lock_address(), other.lock_address());
+    }
+
+    #[test]
+    #[cfg(feature = "parking_lot")]
+    fn timeout_lock() {
+        use crate::data_access::locking_mutate::Lock;
+        use std::time::Duration;
+
+        let da = Da::new(Data::new(1));
+        let oda = Oda::new(Data::new(1));
+
+        let closure = |da: Data, oda: Option<Data>| (da + 1, oda.map(|value| value + 1));
+        assert!(locking_mutate_timeout!(da, oda; Duration::ZERO; closure).is_some());
+        assert!(locking_mutate_timeout!(da; Duration::ZERO; |da: Data| da + 1).is_some());
+
+        // Whilst a lock is held elsewhere, the closure mustn't be called.
+        let lock = Lock::lock(&oda);
+        let closure = |_: Data, _: Option<Data>| -> (Data, Option<Data>) {
+            panic!("The closure mustn't be called")
+        };
+        assert!(locking_mutate_timeout!(da, oda; Duration::from_millis(10); closure).is_none());
+        drop(lock);
+
+        // A timeout too large to represent as a deadline mustn't panic.
+        let closure = |da: Data, oda: Option<Data>| (da + 1, oda.map(|value| value + 1));
+        assert!(locking_mutate_timeout!(da, oda; Duration::MAX; closure).is_some());
+        assert!(locking_mutate_timeout!(da; Duration::MAX; |da: Data| da + 1).is_some());
+
+        assert_eq!(*da.get(), 5.into());
+        assert_eq!(*oda.get().unwrap(), 3.into());
+    }
 }
@@ -1,19 +1,201 @@
 //! Contains structures that allow for quazi concurrent reading & writing of a value.
+//!
+//! # Memory model
+//!
+//! A [`Da`] or [`Oda`] is three words wide, a pointer to the shared lock & a lazily initialised
+//! pointer to the shared callbacks, which are only allocated once a callback is registered or the
+//! data access is cloned. The value itself is behind two levels of heap allocation:
+//!
+//! 1. The outer [`Arc`], shared between every clone of a [`Da`], which contains the reference
+//!    counts, the [`Mutex`] & a pointer to the current value. This is a few words in size,
+//!    regardless of the size of the value.
+//! 2. The inner [`Arc`], which contains the reference counts & the value itself. A new one is
+//!    allocated each time the value is set, which lets references returned from [`Da::get()`]
+//!    outlive the lock.
+//!
+//! As the outer allocation is small, its reference counts, lock & pointer usually share a cache
+//! line, so every access from any clone contends on that one line. The lock is only held long
+//! enough to clone or replace the inner [`Arc`], after which the value is read from its own
+//! allocation without touching the lock. Values which are read very frequently from many threads
+//! still contend on the inner [`Arc`]'s reference count; see [`Da::borrow_arc()`] to avoid it.
 
+#[cfg(feature = "tokio")]
+pub mod async_access;
+pub mod callbacks;
 #[cfg(test)]
 mod detailed_tests;
+pub mod guard;
+pub mod history;
+pub mod iter;
+pub mod lazy;
 pub mod locking_mutate;
+pub mod potential;
+pub mod projected;
+pub mod unique;
 
-use std::{
+use crate::sync::{self, Arc, LockResult, Mutex, MutexGuard, PoisonError, Weak};
+use alloc::{string::String, vec::Vec};
+use callbacks::{CallbackHandle, LazyCallbacks};
+use core::{
+    any::Any,
     clone::Clone,
-    fmt::Debug,
-    sync::{Arc, Mutex},
+    fmt::{Debug, Display, Pointer},
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, DerefMut,
+        DivAssign, Index, Mul, MulAssign, Neg, Not, RemAssign, ShlAssign, ShrAssign, Sub,
+        SubAssign,
+    },
+    str::FromStr,
+};
+use guard::{DaGuard, DaIndexed, OdaGuard};
+use history::DaHistory;
+use iter::{DaOnceIter, OdaOnceIter};
+use locking_mutate::OutOfArc;
+use projected::ProjectedDa;
+#[cfg(feature = "parking_lot")]
+use std::time::Duration;
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    io::{self, Write},
 };
+use unique::UniqueValue;
+
+/// The error returned when the lock on the underlying data is poisoned, containing the guard of
+/// the acquired lock.
+///
+/// This is the same type regardless of which lock backs the data access structs, so enabling the
+/// `parking_lot` feature doesn't change the signatures of methods such as [`Da::try_get()`]. It
+/// can only be returned when using the [`std::sync::Mutex`], as the other locks can't be poisoned.
+pub struct PoisonedLock<'a, Data> {
+    guard: MutexGuard<'a, Data>,
+}
+
+impl<'a, Data> PoisonedLock<'a, Data> {
+    fn new(poisoned: PoisonError<MutexGuard<'a, Data>>) -> Self {
+        Self {
+            guard: poisoned.into_inner(),
+        }
+    }
+
+    /// Returns a reference to the underlying data, ignoring the poison.
+    pub fn get_ref(&self) -> &Data {
+        &self.guard
+    }
+
+    /// Returns a mutable reference to the underlying data, ignoring the poison.
+    pub fn get_mut(&mut self) -> &mut Data {
+        &mut self.guard
+    }
+
+    /// Returns the guard of the acquired lock, ignoring the poison.
+    ///
+    /// The lock is held until the returned guard is dropped.
+    pub fn into_inner(self) -> impl DerefMut<Target = Data> + 'a {
+        self.guard
+    }
+}
+
+impl<Data> Debug for PoisonedLock<'_, Data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PoisonedLock").finish_non_exhaustive()
+    }
+}
+
+impl<Data> Display for PoisonedLock<'_, Data> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(POISONED_MESSAGE)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Data> std::error::Error for PoisonedLock<'_, Data> {}
+
+/// The message used when panicking due to a poisoned lock.
+const POISONED_MESSAGE: &str =
+    "A thread panicked whilst holding the lock on the underlying data. See `recover_from_poison()`";
+
+/// The message used when two data accesses sharing a lock have differing value types.
+pub(crate) const SHARED_LOCK_TYPE_MESSAGE: &str =
+    "Data accesses sharing a lock must share a value type";
+
+/// Acquires the lock on the given [`Mutex`].
+///
+/// If the [`Mutex`] is poisoned this will panic with a descriptive message. Unless the
+/// `recover_poison` feature is enabled, in which case the poison is cleared & the lock is acquired
+/// as normal.
+pub(crate) fn acquire_lock<Data>(mutex: &Mutex<Data>) -> MutexGuard<'_, Data> {
+    try_acquire_lock(mutex).expect(POISONED_MESSAGE)
+}
+
+/// Acquires the lock on the given [`Mutex`], returning an error if it's poisoned.
+///
+/// If the `recover_poison` feature is enabled, the poison is instead cleared & the lock is acquired
+/// as normal, so an error is never returned.
+pub(crate) fn try_acquire_lock<Data>(mutex: &Mutex<Data>) -> LockResult<MutexGuard<'_, Data>> {
+    #[cfg(feature = "recover_poison")]
+    {
+        Ok(sync::lock(mutex).unwrap_or_else(|poisoned| {
+            sync::clear_poison(mutex);
+            poisoned.into_inner()
+        }))
+    }
+
+    #[cfg(not(feature = "recover_poison"))]
+    {
+        sync::lock(mutex)
+    }
+}
+
+/// Acquires the locks on both of the given [`Mutex`]es, in the order of their addresses.
+///
+/// Always locking the lower address first ensures that two threads locking the same pair of
+/// [`Mutex`]es in opposing argument orders can't deadlock.
+///
+/// Returns `None` if both [`Arc`]s point to the same [`Mutex`], as it can't be locked twice.
+fn acquire_ordered_locks<'a, Data>(
+    first: &'a Arc<Mutex<Data>>,
+    second: &'a Arc<Mutex<Data>>,
+) -> Option<(MutexGuard<'a, Data>, MutexGuard<'a, Data>)> {
+    if Arc::ptr_eq(first, second) {
+        return None;
+    }
+
+    if Arc::as_ptr(first) < Arc::as_ptr(second) {
+        let first = acquire_lock(first);
+        Some((first, acquire_lock(second)))
+    } else {
+        let second = acquire_lock(second);
+        Some((acquire_lock(first), second))
+    }
+}
+
+/// Sorts the given data accesses by the address of their locks, removing any which share a lock.
+fn sorted_by_lock<'a, DataAccess>(
+    data_accesses: &[&'a DataAccess],
+    lock_address: fn(&DataAccess) -> usize,
+) -> Vec<&'a DataAccess> {
+    let mut data_accesses = data_accesses.to_vec();
+    data_accesses.sort_unstable_by_key(|data_access| lock_address(data_access));
+    data_accesses.dedup_by_key(|data_access| lock_address(data_access));
+    data_accesses
+}
 
 /// [`OptionalDataAccess`](Oda)
 /// ---
 ///
 /// Facilitates "concurrent" reading & writing for the given (optional) value.
+///
+/// # Layout
+///
+/// This can't be `#[repr(transparent)]`, as it contains both the shared lock & the shared
+/// callbacks, so its layout isn't guaranteed. See [`Self::as_raw_ptr()`] to identify the shared
+/// state, and the [module documentation](self) for how the value is stored.
+///
+/// # Unwind safety
+///
+/// See [`Da`], as the same locks are used.
 pub struct Oda<Value>
 where
     Value: 'static,
@@ -27,6 +209,8 @@ where
     /// With the [`Mutex`] being wrapped in an [`Arc`] to allow multiple instances to point to same
     /// data, as it will be stored on the heap.
     pub(super) current_ref: Arc<Mutex<Option<Arc<Value>>>>,
+    /// The callbacks registered through [`Oda::on_set()`], shared between clones.
+    callbacks: LazyCallbacks<Option<Arc<Value>>>,
 }
 
 impl<Value> Oda<Value>
@@ -37,6 +221,22 @@ where
     pub fn new(data: Value) -> Self {
         Self {
             current_ref: Arc::new(Mutex::new(Some(Arc::new(data)))),
+            callbacks: Default::default(),
+        }
+    }
+
+    /// Creates a new [`Oda<Value>`] containing the value returned by the given function, or no
+    /// value if it returns `None`.
+    ///
+    /// The function is called immediately. See [`LazyOda`](lazy::LazyOda) to call it on first
+    /// access instead.
+    pub fn from_fn<Func>(func: Func) -> Self
+    where
+        Func: FnOnce() -> Option<Value>,
+    {
+        Self {
+            current_ref: Arc::new(Mutex::new(func().map(Arc::new))),
+            callbacks: Default::default(),
         }
     }
 
@@ -44,135 +244,277 @@ where
     pub fn acquire(value_reference: Arc<Value>) -> Self {
         Self {
             current_ref: Arc::new(Mutex::new(Some(value_reference))),
+            callbacks: Default::default(),
         }
     }
 
-    /// Gets a reference to the current underlying data.
+    /// Returns the address of the lock on the underlying data.
     ///
-    /// This reference **will be uneffected** by any subsequent mutations.
-    pub fn get(&self) -> Option<Arc<Value>> {
-        self.current_ref.lock().unwrap().clone()
+    /// See [`Da::lock_address()`] for more information.
+    pub(crate) fn lock_address(&self) -> usize {
+        self.mutex_ptr() as usize
     }
 
-    /// Allows for a value that implements [`Copy`] to be copied out of [`Oda`]. (If a value is present).
+    /// Returns a pointer to the lock on the underlying data.
     ///
-    /// This copy is in no way related to the underlying data other than by it's value of the time
-    /// of copying.
-    pub fn copy_value(&self) -> Option<Value>
-    where
-        Value: Copy,
-    {
-        self.current_ref
-            .lock()
-            .unwrap()
-            .as_ref()
-            .map(|arc_ref| **arc_ref)
+    /// See [`Da::mutex_ptr()`] for more information, and [`Self::as_raw_ptr()`], which returns the
+    /// same pointer.
+    pub fn mutex_ptr(&self) -> *const () {
+        Arc::as_ptr(&self.current_ref).cast()
     }
 
-    /// Creates new underlying data with the given value; Returning the old value. All references
-    /// to the old data will continue to remain valid.
+    /// Returns `true` if both [`Oda`]s currently reference the same allocation of underlying data.
+    /// Returns `false` if either has no data.
     ///
-    /// Any subsequent calls to [`get`](Self::get()) will return the new data.
+    /// See [`Da::ptr_equal()`] for more information.
+    pub fn ptr_equal(&self, other: &Self) -> bool {
+        match (self.get(), other.get()) {
+            (Some(data), Some(other_data)) => Arc::ptr_eq(&data, &other_data),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if both [`Oda`]s share the same lock, meaning one is a clone of the other.
     ///
-    /// Any existing references from [`get`](Self::get()) will remain pointing to the old data.
-    pub fn set(&self, new_data: Value) -> Option<Arc<Value>> {
-        let mut old_data = self.current_ref.lock().unwrap();
-        old_data.replace(Arc::new(new_data))
+    /// See [`Da::state_shared()`] for more information.
+    pub fn state_shared(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.current_ref, &other.current_ref)
     }
 
-    /// Replaces the the [`Arc`] contained within [`Self`] to the given [`Arc`]. The given [`Arc`] is
-    /// held via a strong reference.
+    /// Creates a new [`Oda`] containing a clone of the current underlying data, which doesn't
+    /// share state with this [`Oda`].
     ///
-    /// See [`Self::set()`] for more information on the behaviour of current & future references.
-    pub fn replace(&self, data_arc: Option<Arc<Value>>) {
-        let mut old_data = self.current_ref.lock().unwrap();
-        *old_data = data_arc;
+    /// See [`Da::clone_independent()`] for more information.
+    pub fn clone_independent(&self) -> Self
+    where
+        Value: Clone,
+    {
+        Self {
+            current_ref: Arc::new(Mutex::new(self.get_cloned().map(Arc::new))),
+            callbacks: Default::default(),
+        }
     }
 
-    /// Takes the value out of the [`Oda`], leaving `None` in its place.
+    /// Returns the amount of references to the current underlying data, or `0` if there is none.
     ///
-    /// See [`Self::set()`] for more information on the behaviour of current & future references.
-    pub fn empty(&self) -> Option<Arc<Value>> {
-        self.current_ref.lock().unwrap().take()
+    /// See [`Da::value_strong_count()`] for more information.
+    pub fn value_strong_count(&self) -> usize {
+        acquire_lock(&self.current_ref)
+            .as_ref()
+            .map_or(0, Arc::strong_count)
     }
 
-    /// If there is underlying data, it's cloned & the given function will be called with it as the parameter.
-    /// The value returned from the function will be set as the new underlying data.
-    /// If there is no data then this method **has no effect**.
+    /// Returns the amount of [`Oda`]s sharing state with this [`Oda`], including itself.
+    pub fn outer_strong_count(&self) -> usize {
+        Arc::strong_count(&self.current_ref)
+    }
+
+    /// Returns a pointer to the shared state of this [`Oda`], which is the same for every
+    /// [`Oda`] sharing state with it.
     ///
-    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
-    /// It only waits to acquire a lock when it's reading the value for the initial clone & when writing the
-    /// mutated value back to the [`Oda`].
-    /// Due to cloning the data out of the [`Oda`], the value passed into the function **is immutable**.
+    /// Only intended for identifying the shared state, such as when debugging. The pointer
+    /// **must not** be dereferenced, as the layout of the shared state isn't guaranteed. This is
+    /// the same pointer as [`Self::mutex_ptr()`].
+    pub fn as_raw_ptr(&self) -> *const () {
+        self.mutex_ptr()
+    }
+
+    /// Converts this [`Oda`] into an iterator which yields the underlying data once, or nothing if
+    /// there is no data.
+    pub fn into_iter_once(self) -> OdaOnceIter<Value> {
+        OdaOnceIter {
+            data_access: Some(self),
+        }
+    }
+
+    /// Creates a new [`Oda<Value>`] by parsing the given string.
     ///
-    /// See [`locking_mutate!()`](crate::data_access::locking_mutate::locking_mutate) if you want a persistent lock.
+    /// See [`Da::parse()`] for more information.
+    pub fn parse<Str>(string: Str) -> Result<Self, Value::Err>
+    where
+        Value: FromStr,
+        Str: AsRef<str>,
+    {
+        string.as_ref().parse().map(Self::new)
+    }
+
+    /// Creates a new [`Oda<Value>`] by converting the given input.
     ///
-    /// See [`Self::set()`] for more information on the behaviour of current & future references.
-    pub fn mutate<Func>(&self, func: Func)
+    /// See [`Da::try_from_value()`] for more information.
+    pub fn try_from_value<Input>(input: Input) -> Result<Self, Value::Error>
+    where
+        Value: TryFrom<Input>,
+    {
+        Value::try_from(input).map(Self::new)
+    }
+
+    /// Creates a new [`Oda`] by converting a clone of the underlying data. If there is no data, the
+    /// new [`Oda`] also has no data.
+    ///
+    /// See [`Da::try_convert()`] for more information.
+    pub fn try_convert<Output>(&self) -> Result<Oda<Output>, Output::Error>
     where
         Value: Clone,
-        Func: FnOnce(Value) -> Value,
+        Output: TryFrom<Value>,
     {
-        if let Some(old_value) = self.get() {
-            let mutated_value = func((*old_value).clone());
-            self.set(mutated_value);
+        match self.get_cloned() {
+            Some(value) => Output::try_from(value).map(Oda::new),
+            None => Ok(Oda::default()),
         }
     }
-}
 
-/// [`DataAccess`](Da)
-/// ---
-///
-/// Facilitates "concurrent" reading & writing for the given value.
-pub struct Da<Value>
-where
-    Value: 'static,
-{
-    /// Contains the data being represented.
-    /// ---
+    /// Gets a reference to the current underlying data.
     ///
-    /// The innermost `Value` is surrounded by an [`Arc`], so that it lives on the heap & can outlive
-    /// the reference to it from this struct.
+    /// This reference **will be uneffected** by any subsequent mutations.
+    pub fn get(&self) -> Option<Arc<Value>> {
+        self.try_get().expect(POISONED_MESSAGE)
+    }
+
+    /// Gets a reference to the current underlying data, returning an error if the lock is poisoned.
     ///
-    /// With the [`Mutex`] being wrapped in an [`Arc`] to allow multiple instances to point to same
-    /// data, as it will be stored on the heap.
-    pub(super) current_ref: Arc<Mutex<Arc<Value>>>,
-}
+    /// See [`Self::get()`] for more information.
+    pub fn try_get(&self) -> Result<Option<Arc<Value>>, PoisonedLock<'_, Option<Arc<Value>>>> {
+        try_acquire_lock(&self.current_ref)
+            .map(|data| data.clone())
+            .map_err(PoisonedLock::new)
+    }
 
-impl<Value> Da<Value>
-where
-    Value: 'static,
-{
-    /// Creates a new [`Da<Value>`].
-    pub fn new(data: Value) -> Self {
-        Self {
-            current_ref: Arc::new(Mutex::new(Arc::new(data))),
-        }
+    /// Gets a reference to the current underlying data, panicking with the given message if there
+    /// is none.
+    ///
+    /// See [`Self::get()`] for more information.
+    #[track_caller]
+    pub fn expect(&self, msg: &str) -> Arc<Value> {
+        self.get().expect(msg)
     }
 
-    /// Creates a new [`Oda<Value>`] which references the given [`Arc`].
-    pub fn acquire(value_reference: Arc<Value>) -> Self {
-        Self {
-            current_ref: Arc::new(Mutex::new(value_reference)),
+    /// Gets a reference to the current underlying data, panicking if there is none.
+    ///
+    /// See [`Self::get()`] for more information.
+    #[track_caller]
+    pub fn unwrap(&self) -> Arc<Value> {
+        self.expect("Called `Oda::unwrap()` on an `Oda` with no data")
+    }
+
+    /// Acquires the lock on the underlying data, returning a guard which provides read access to it.
+    /// The lock is held until the guard is dropped.
+    ///
+    /// See [`Da::lock_guard()`] for more information.
+    pub fn lock_guard(&self) -> OdaGuard<'_, Value> {
+        OdaGuard {
+            guard: acquire_lock(&self.current_ref),
         }
     }
 
-    /// Gets a reference to the current underlying data.
+    /// Calls the given function with a reference to the underlying data, if there is any,
+    /// returning [`Self`] to allow for chaining.
     ///
-    /// This reference **will be uneffected** by any subsequent mutations.
-    pub fn get(&self) -> Arc<Value> {
-        self.current_ref.lock().unwrap().clone()
+    /// See [`Da::inspect()`] for more information.
+    pub fn inspect<Func>(&self, func: Func) -> &Self
+    where
+        Func: FnOnce(Option<&Value>),
+    {
+        func(acquire_lock(&self.current_ref).as_deref());
+        self
     }
 
-    /// Allows for a value that implements [`Copy`] to be copied out of [`Da`].
+    /// Calls the given function with a reference to the underlying data, if there is any,
+    /// returning the result.
+    ///
+    /// See [`Da::as_ref_with()`] for more information.
+    pub fn as_ref_with<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(Option<&Value>) -> Output,
+    {
+        func(acquire_lock(&self.current_ref).as_deref())
+    }
+
+    /// Alias of [`Self::as_ref_with()`].
+    pub fn borrow_current<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(Option<&Value>) -> Output,
+    {
+        self.as_ref_with(func)
+    }
+
+    /// Calls the given function with a reference to the [`Arc`] of the underlying data, if there is
+    /// any, returning the result.
+    ///
+    /// See [`Da::borrow_arc()`] for more information.
+    pub fn borrow_arc_opt<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(Option<&Arc<Value>>) -> Output,
+    {
+        func(acquire_lock(&self.current_ref).as_ref())
+    }
+
+    /// Returns a weak reference to the underlying data, or `None` if there is no data.
+    ///
+    /// See [`Da::get_weak()`] for more information.
+    pub fn get_weak(&self) -> Option<Weak<Value>> {
+        self.borrow_arc_opt(|data| data.map(Arc::downgrade))
+    }
+
+    /// Calls the given function with the current underlying data if there is any, returning the
+    /// result.
+    ///
+    /// See [`Da::pipe()`] for more information.
+    pub fn pipe_some<Output, Func>(&self, func: Func) -> Option<Output>
+    where
+        Func: FnOnce(Arc<Value>) -> Output,
+    {
+        self.get().map(func)
+    }
+
+    /// Allows for a value that implements [`Copy`] to be copied out of [`Oda`]. (If a value is present).
     ///
     /// This copy is in no way related to the underlying data other than by it's value of the time
     /// of copying.
-    pub fn copy_value(&self) -> Value
+    pub fn copy_value(&self) -> Option<Value>
     where
         Value: Copy,
     {
-        **self.current_ref.lock().unwrap()
+        acquire_lock(&self.current_ref)
+            .as_ref()
+            .map(|arc_ref| **arc_ref)
+    }
+
+    /// Sets the underlying data to the given value, returning a copy of the old value.
+    ///
+    /// See [`Da::copy_and_set()`] for more information.
+    pub fn copy_and_set(&self, new_data: Option<Value>) -> Option<Value>
+    where
+        Value: Copy,
+    {
+        let data = acquire_lock(&self.current_ref);
+        let old_value = data.as_deref().copied();
+        self.replace_and_notify(data, new_data.map(Arc::new));
+        old_value
+    }
+
+    /// Clones the underlying data out of the [`Oda`], if there is any.
+    ///
+    /// See [`Da::get_cloned()`] for more information.
+    pub fn get_cloned(&self) -> Option<Value>
+    where
+        Value: Clone,
+    {
+        self.get().map(|value| (*value).clone())
+    }
+
+    /// Clears the poison from the lock on the underlying data, if a thread panicked whilst holding it.
+    /// Returns `true` if the lock was poisoned & has been recovered, or `false` if the lock was healthy.
+    ///
+    /// The underlying data is left as it was when the panicking thread released the lock.
+    pub fn recover_from_poison(&self) -> bool {
+        match sync::lock(&self.current_ref) {
+            Ok(_) => false,
+            Err(poisoned) => {
+                drop(poisoned.into_inner());
+                sync::clear_poison(&self.current_ref);
+                true
+            }
+        }
     }
 
     /// Creates new underlying data with the given value; Returning the old value. All references
@@ -181,483 +523,4353 @@ where
     /// Any subsequent calls to [`get`](Self::get()) will return the new data.
     ///
     /// Any existing references from [`get`](Self::get()) will remain pointing to the old data.
-    pub fn set(&self, new_data: Value) -> Arc<Value> {
-        let mut data = self.current_ref.lock().unwrap();
-        let old_data = Arc::clone(&*data);
-        *data = Arc::new(new_data);
-        old_data
+    pub fn set(&self, new_data: Value) -> Option<Arc<Value>> {
+        self.try_set(new_data).expect(POISONED_MESSAGE)
     }
 
-    /// Replaces the the [`Arc`] contained within [`Self`] to the given [`Arc`]. The given [`Arc`] is
-    /// held via a strong reference.
+    /// Creates new underlying data with the given value; Returning the old value, or an error if
+    /// the lock is poisoned.
     ///
     /// See [`Self::set()`] for more information on the behaviour of current & future references.
-    pub fn replace(&self, data_arc: Arc<Value>) {
-        let mut old_data = self.current_ref.lock().unwrap();
-        *old_data = data_arc;
+    pub fn try_set(
+        &self,
+        new_data: Value,
+    ) -> Result<Option<Arc<Value>>, PoisonedLock<'_, Option<Arc<Value>>>> {
+        let data = try_acquire_lock(&self.current_ref).map_err(PoisonedLock::new)?;
+        Ok(self.replace_and_notify(data, Some(Arc::new(new_data))))
     }
 
-    /// Clones the existing underlying data & calls the given function with the clone as the parameter.
-    /// The value returned from the function will be set as the new underlying data.
+    /// Gets a reference to the current underlying data, or `None` if the lock couldn't be acquired
+    /// within the given timeout.
     ///
-    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
-    /// It only waits to acquire a lock when it's reading the value for the initial clone & when writing the
-    /// mutated value back to the [`Da`].
-    /// Due to cloning the data out of the [`Da`], the value passed into the function **is immutable**.
+    /// See [`Da::get_timeout()`] for more information.
+    #[cfg(feature = "parking_lot")]
+    pub fn get_timeout(&self, timeout: Duration) -> Option<Option<Arc<Value>>> {
+        sync::lock_timeout(&self.current_ref, timeout).map(|data| data.clone())
+    }
+
+    /// Creates new underlying data with the given value, returning `false` if the lock couldn't be
+    /// acquired within the given timeout.
     ///
-    /// See [`locking_mutate!()`](crate::data_access::locking_mutate::locking_mutate) if you want a persistent lock.
+    /// See [`Da::set_timeout()`] for more information.
+    #[cfg(feature = "parking_lot")]
+    pub fn set_timeout(&self, new_data: Value, timeout: Duration) -> bool {
+        match sync::lock_timeout(&self.current_ref, timeout) {
+            Some(data) => {
+                self.replace_and_notify(data, Some(Arc::new(new_data)));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the underlying data to the given value, only if it differs from the current value.
+    /// Returns `true` if the underlying data was changed.
+    ///
+    /// The lock is held whilst comparing, so no other mutation can occur in-between. If the values
+    /// are equal, the existing data is kept & no new allocation is made.
     ///
     /// See [`Self::set()`] for more information on the behaviour of current & future references.
-    pub fn mutate<Func>(&self, func: Func)
+    pub fn conditional_set(&self, new_data: Option<Value>) -> bool
     where
-        Value: Clone,
-        Func: FnOnce(Value) -> Value,
+        Value: PartialEq,
     {
-        let mutated_value = func((*self.get()).clone());
-        self.set(mutated_value);
-    }
-}
-
-impl<Value> PartialEq for Oda<Value>
-where
-    Value: PartialEq + 'static,
-{
-    fn eq(&self, other: &Self) -> bool {
-        match (self.get(), other.get()) {
-            (None, None) => true,
-            (None, Some(_)) | (Some(_), None) => false,
-            (Some(self_data), Some(other_data)) => *self_data == *other_data,
+        let data = acquire_lock(&self.current_ref);
+        if data.as_deref() == new_data.as_ref() {
+            return false;
         }
+
+        self.replace_and_notify(data, new_data.map(Arc::new));
+        true
     }
-}
 
-impl<Value> Debug for Oda<Value>
-where
-    Value: Debug + 'static,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Oda")
-            .field("current_ref", &self.get())
-            .finish()
+    /// Sets the underlying data to the given [`Arc`], or to no data if `None` is given; Returning
+    /// the old value.
+    ///
+    /// See [`Da::set_arc()`] for how this differs from [`Self::set()`].
+    pub fn set_arc(&self, data_arc: Option<Arc<Value>>) -> Option<Arc<Value>> {
+        self.replace_and_notify(acquire_lock(&self.current_ref), data_arc)
     }
-}
 
-impl<Value> From<Value> for Oda<Value>
-where
-    Value: 'static,
-{
-    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
-    fn from(value: Value) -> Self {
-        Self::new(value)
+    /// Replaces the the [`Arc`] contained within [`Self`] to the given [`Arc`]. The given [`Arc`] is
+    /// held via a strong reference.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    #[deprecated(note = "Renamed to `set_arc`, to match `set`")]
+    pub fn replace(&self, data_arc: Option<Arc<Value>>) {
+        self.set_arc(data_arc);
     }
-}
 
-impl<Value> Default for Oda<Value>
-where
-    Value: 'static,
-{
-    /// Idiomatic to calling [`Self::new(None)`](Self::new()).
-    fn default() -> Self {
-        Self {
-            current_ref: Arc::new(Mutex::new(None)),
+    /// Registers the given callback, which is called with the new underlying data whenever it's
+    /// changed.
+    ///
+    /// See [`Da::on_set()`] for more information, including which changes call the callbacks.
+    pub fn on_set<Func>(&self, callback: Func) -> CallbackHandle
+    where
+        Func: Fn(Option<Arc<Value>>) + Send + 'static,
+    {
+        callbacks::register(&self.callbacks, callback)
+    }
+
+    /// Replaces the underlying data, then releases the given lock & calls the
+    /// [`on_set`](Self::on_set()) callbacks; Returning the old value.
+    ///
+    /// See [`Da::replace_and_notify()`] for more information.
+    fn replace_and_notify(
+        &self,
+        mut data: MutexGuard<'_, Option<Arc<Value>>>,
+        new_data: Option<Arc<Value>>,
+    ) -> Option<Arc<Value>> {
+        let old_data = core::mem::replace(&mut *data, new_data.clone());
+        drop(data);
+        callbacks::notify(&self.callbacks, new_data);
+        old_data
+    }
+
+    /// Sets the underlying data of every given [`Oda`] to the given value, whilst holding all of
+    /// their locks.
+    ///
+    /// See [`Da::fanout()`] for more information.
+    pub fn fanout(targets: &[&Oda<Value>], new_data: Value) {
+        let new_data = Arc::new(new_data);
+        let targets = sorted_by_lock(targets, Self::lock_address);
+
+        let mut locks: Vec<_> = targets
+            .iter()
+            .map(|target| acquire_lock(&target.current_ref))
+            .collect();
+        for data in locks.iter_mut() {
+            **data = Some(new_data.clone());
+        }
+        // Every lock must be released before any callback is called, so this can't go through
+        // `replace_and_notify()`.
+        drop(locks);
+
+        for target in targets {
+            callbacks::notify(&target.callbacks, Some(new_data.clone()));
         }
     }
-}
 
-impl<Value> Clone for Oda<Value>
-where
-    Value: 'static,
-{
-    /// Creates a new [`Oda`] pointing to the **exact same** value as the original [`Oda`].
-    fn clone(&self) -> Self {
-        Self {
-            current_ref: self.current_ref.clone(),
+    /// Takes the value out of the [`Oda`], leaving `None` in its place.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn take(&self) -> Option<Arc<Value>> {
+        self.replace_and_notify(acquire_lock(&self.current_ref), None)
+    }
+
+    /// Exchanges the underlying data of this [`Oda`] with the underlying data of the other [`Oda`].
+    ///
+    /// Both locks are held whilst the data is being exchanged, so no other mutation can occur
+    /// in-between. If both [`Oda`]s point to the same data, this method has no effect.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn swap_with(&self, other: &Oda<Value>) {
+        if let Some((mut data, mut other_data)) =
+            acquire_ordered_locks(&self.current_ref, &other.current_ref)
+        {
+            core::mem::swap(&mut *data, &mut *other_data);
+
+            // Both locks must be released before either callback is called.
+            let (new_data, other_new_data) = (data.clone(), other_data.clone());
+            drop((data, other_data));
+            callbacks::notify(&self.callbacks, new_data);
+            callbacks::notify(&other.callbacks, other_new_data);
         }
     }
-}
 
-impl<Value> Debug for Da<Value>
-where
-    Value: Debug + 'static,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Da")
-            .field("current_ref", &self.get())
-            .finish()
+    /// Sets the underlying data to the value returned by the given function, which is called with
+    /// clones of the underlying data of both [`Oda`]s. The other [`Oda`] is left unmodified.
+    ///
+    /// See [`Da::merge_with()`] for more information.
+    pub fn merge_with<Func>(&self, other: &Oda<Value>, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Option<Value>, Option<Value>) -> Option<Value>,
+    {
+        match acquire_ordered_locks(&self.current_ref, &other.current_ref) {
+            Some((data, other_data)) => {
+                let new_data = func(data.ooa(), other_data.ooa()).map(Arc::new);
+                drop(other_data);
+                self.replace_and_notify(data, new_data);
+            }
+            // Both point to the same data, so it's merged with itself.
+            None => {
+                let data = acquire_lock(&self.current_ref);
+                let new_data = func(data.ooa(), data.ooa()).map(Arc::new);
+                self.replace_and_notify(data, new_data);
+            }
+        }
     }
-}
 
-impl<Value> Default for Da<Value>
-where
-    Value: Default + 'static,
-{
-    /// Idiomatic to calling [`Self::new(value::default())`](Self::new()).
-    fn default() -> Self {
-        Self {
-            current_ref: Arc::new(Mutex::new(Arc::new(Value::default()))),
+    /// Creates a new [`Da`] from the value returned by the given function, which is called with the
+    /// underlying data of both [`Oda`]s.
+    ///
+    /// See [`Da::zip_with()`] for more information.
+    pub fn zip_with<Other, Output, Func>(&self, other: &Oda<Other>, func: Func) -> Da<Output>
+    where
+        Func: FnOnce(Option<&Value>, Option<&Other>) -> Output,
+    {
+        if self.lock_address() == other.lock_address() {
+            let data = acquire_lock(&self.current_ref);
+            let other_data = data.as_deref().map(|value| {
+                (value as &dyn Any)
+                    .downcast_ref()
+                    .expect(SHARED_LOCK_TYPE_MESSAGE)
+            });
+            return Da::new(func(data.as_deref(), other_data));
         }
+
+        let (data, other_data) = match self.lock_address() < other.lock_address() {
+            true => {
+                let data = acquire_lock(&self.current_ref);
+                (data, acquire_lock(&other.current_ref))
+            }
+            false => {
+                let other_data = acquire_lock(&other.current_ref);
+                (acquire_lock(&self.current_ref), other_data)
+            }
+        };
+
+        Da::new(func(data.as_deref(), other_data.as_deref()))
     }
-}
 
-impl<Value> From<Value> for Da<Value>
-where
-    Value: 'static,
-{
-    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
-    fn from(value: Value) -> Self {
-        Self::new(value)
+    /// Returns a new [`Oda`] containing clones of the underlying data of both [`Oda`]s, or no data
+    /// if either has no data, like [`Option::zip()`].
+    ///
+    /// See [`Self::zip_some_with()`] for more information.
+    pub fn zip<Other>(&self, other: &Oda<Other>) -> Oda<(Value, Other)>
+    where
+        Value: Clone,
+        Other: Clone + 'static,
+    {
+        self.zip_some_with(other, |value, other| (value, other))
     }
-}
 
-impl<Value> Clone for Da<Value>
-where
-    Value: 'static,
-{
-    /// Creates a new [`Da`] pointing to the **exact same** value as the original [`Da`].
-    fn clone(&self) -> Self {
-        Self {
-            current_ref: self.current_ref.clone(),
+    /// Returns a new [`Oda`] containing the value returned by the given function, which is called
+    /// with clones of the underlying data of both [`Oda`]s. If either has no data, the function
+    /// isn't called & the returned [`Oda`] has no data.
+    ///
+    /// Unlike [`Self::zip_with()`], the function is only called when both have data & the locks
+    /// aren't held whilst it's executing. The data is cloned whilst holding both locks, so the
+    /// values are read at the same point in time. The returned [`Oda`] **is not** updated by any
+    /// subsequent mutations to either [`Oda`].
+    pub fn zip_some_with<Other, Output, Func>(&self, other: &Oda<Other>, func: Func) -> Oda<Output>
+    where
+        Value: Clone,
+        Other: Clone + 'static,
+        Output: 'static,
+        Func: FnOnce(Value, Other) -> Output,
+    {
+        let (value, other_value) = if self.lock_address() == other.lock_address() {
+            let data = acquire_lock(&self.current_ref);
+            let other_value = data.as_deref().map(|value| {
+                (value as &dyn Any)
+                    .downcast_ref::<Other>()
+                    .expect(SHARED_LOCK_TYPE_MESSAGE)
+                    .clone()
+            });
+            (data.as_deref().cloned(), other_value)
+        } else {
+            let (data, other_data) = match self.lock_address() < other.lock_address() {
+                true => {
+                    let data = acquire_lock(&self.current_ref);
+                    (data, acquire_lock(&other.current_ref))
+                }
+                false => {
+                    let other_data = acquire_lock(&other.current_ref);
+                    (acquire_lock(&self.current_ref), other_data)
+                }
+            };
+            (data.as_deref().cloned(), other_data.as_deref().cloned())
+        };
+
+        // Both locks have been released, so the function is free to access either Oda.
+        match value.zip(other_value) {
+            Some((value, other)) => Oda::new(func(value, other)),
+            None => Oda::default(),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// If there is underlying data, it's set to the value returned from the given function,
+    /// returning the **old** value. If there is no data then this method **has no effect**.
+    ///
+    /// The lock on the underlying data is held whilst the function is executing, so the returned
+    /// value is guaranteed to be the value the function was called with.
+    fn fetch_update<Func>(&self, func: Func) -> Option<Value>
+    where
+        Value: Copy,
+        Func: FnOnce(Value) -> Value,
+    {
+        let data = acquire_lock(&self.current_ref);
+        let old_value = **data.as_ref()?;
+        self.replace_and_notify(data, Some(Arc::new(func(old_value))));
+        Some(old_value)
+    }
 
-    #[derive(Clone, Debug, Default, PartialEq, Eq)]
-    struct DummyData {
-        text: String,
-        num: u64,
+    /// Atomically adds the given value to the underlying data, returning the **old** value.
+    /// If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_add()`](std::sync::atomic::AtomicUsize::fetch_add()) for the
+    /// comparable atomic operation, which wraps on overflow rather than panicking.
+    ///
+    /// # Panics
+    ///
+    /// If the addition overflows in a debug build, the same as the operator. The lock is held
+    /// whilst the new value is calculated, so this also poisons the lock, unless the lock can't be
+    /// poisoned. See [`Self::recover_from_poison()`].
+    pub fn fetch_add(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + Add<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value + value)
     }
 
-    impl DummyData {
-        fn new(text: &str, num: u64) -> Self {
-            let text = text.to_owned();
-            Self { text, num }
-        }
+    /// Atomically subtracts the given value from the underlying data, returning the **old** value.
+    /// If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_sub()`](std::sync::atomic::AtomicUsize::fetch_sub()) for the
+    /// comparable atomic operation, which wraps on overflow rather than panicking.
+    ///
+    /// # Panics
+    ///
+    /// If the subtraction overflows in a debug build, the same as the operator. The lock is held
+    /// whilst the new value is calculated, so this also poisons the lock, unless the lock can't be
+    /// poisoned. See [`Self::recover_from_poison()`].
+    pub fn fetch_sub(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + Sub<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value - value)
     }
 
-    #[cfg(test)]
-    mod data_access {
-        use std::thread;
+    /// Atomically multiplies the given value with the underlying data, returning the **old** value.
+    /// If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// # Panics
+    ///
+    /// If the multiplication overflows in a debug build, the same as the operator. The lock is held
+    /// whilst the new value is calculated, so this also poisons the lock, unless the lock can't be
+    /// poisoned. See [`Self::recover_from_poison()`].
+    pub fn fetch_mul(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + Mul<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value * value)
+    }
 
-        use super::*;
+    /// Atomically performs a bitwise "and" of the given value with the underlying data, returning the **old** value.
+    /// If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_and()`](std::sync::atomic::AtomicUsize::fetch_and()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_and(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + BitAnd<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value & value)
+    }
 
-        fn get_default() -> Da<DummyData> {
-            Da::new(DummyData::default())
-        }
+    /// Atomically performs a bitwise "or" of the given value with the underlying data, returning the **old** value.
+    /// If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_or()`](std::sync::atomic::AtomicUsize::fetch_or()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_or(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + BitOr<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value | value)
+    }
 
-        #[test]
-        fn holds_data() {
-            let data_access = Da::new(DummyData::default());
-            assert_eq!(*data_access.get(), DummyData::default());
-        }
+    /// Atomically performs a bitwise "xor" of the given value with the underlying data, returning the **old** value.
+    /// If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_xor()`](std::sync::atomic::AtomicUsize::fetch_xor()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_xor(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + BitXor<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value ^ value)
+    }
 
-        #[test]
-        fn mutate_data() {
-            let data_access = get_default();
-            let mutated_dummy = DummyData::new("I'm different!", 0);
+    /// Atomically sets the underlying data to the maximum of it & the given value, returning the
+    /// **old** value. If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_max()`](std::sync::atomic::AtomicUsize::fetch_max()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_max(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + Ord,
+    {
+        self.fetch_update(|old_value| old_value.max(value))
+    }
 
-            data_access.set(DummyData::new("I'm different!", 0));
-            assert_eq!(*data_access.get(), mutated_dummy);
+    /// Atomically sets the underlying data to the minimum of it & the given value, returning the
+    /// **old** value. If there is no data then `None` is returned & this method **has no effect**.
+    ///
+    /// See [`AtomicUsize::fetch_min()`](std::sync::atomic::AtomicUsize::fetch_min()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_min(&self, value: Value) -> Option<Value>
+    where
+        Value: Copy + Ord,
+    {
+        self.fetch_update(|old_value| old_value.min(value))
+    }
+
+    /// Atomically inverts the underlying data, such as flipping a `bool`. If there is no data then
+    /// this method **has no effect**.
+    pub fn toggle(&self)
+    where
+        Value: Copy + Not<Output = Value>,
+    {
+        self.fetch_update(|value| !value);
+    }
+
+    /// Takes the value out of the [`Oda`], leaving `None` in its place.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    #[deprecated = "Use `Oda::take()` instead, which matches `Option::take()`."]
+    pub fn empty(&self) -> Option<Arc<Value>> {
+        self.take()
+    }
+
+    /// If there is underlying data, it's cloned & the given function will be called with it as the parameter.
+    /// The value returned from the function will be set as the new underlying data.
+    /// If there is no data then this method **has no effect**.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
+    /// It only waits to acquire a lock when it's reading the value for the initial clone & when writing the
+    /// mutated value back to the [`Oda`].
+    /// Due to cloning the data out of the [`Oda`], the value passed into the function **is immutable**.
+    ///
+    /// See [`locking_mutate!()`](crate::data_access::locking_mutate::locking_mutate) if you want a persistent lock.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        self.try_mutate(func).expect(POISONED_MESSAGE)
+    }
+
+    /// The same as [`Self::mutate()`], but returns an error if the lock is poisoned.
+    pub fn try_mutate<Func>(&self, func: Func) -> Result<(), PoisonedLock<'_, Option<Arc<Value>>>>
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        if let Some(old_value) = self.try_get()? {
+            let mutated_value = func((*old_value).clone());
+            self.try_set(mutated_value)?;
         }
+        Ok(())
+    }
 
-        #[test]
-        fn old_reference() {
-            let data_access = get_default();
-            let mutated_dummy = DummyData::new("I'm different!", 0);
+    /// If there is underlying data, it's cloned & the given function will be called with it as the
+    /// parameter. The value returned from the function will be set as the new underlying data.
+    /// Returns the data from before & after the mutation, which are both `None` if there was no data.
+    ///
+    /// Unlike [`Self::mutate()`], the lock on the underlying data **is held** whilst the given
+    /// function is executing, so no other mutation can occur between the returned values.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn mutate_with_previous<Func>(&self, func: Func) -> (Option<Arc<Value>>, Option<Arc<Value>>)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        let data = acquire_lock(&self.current_ref);
+        let Some(old_data) = data.clone() else {
+            return (None, None);
+        };
 
-            // Get a reference to the original data
-            let initial_content = data_access.get();
+        let new_data = Arc::new(func((*old_data).clone()));
+        self.replace_and_notify(data, Some(new_data.clone()));
+        (Some(old_data), Some(new_data))
+    }
 
-            // Set new data
-            data_access.set(DummyData::new("I'm different!", 0));
+    /// Calls the given function with a clone of the underlying data, or `None` if there is no
+    /// data. The first value returned from the function is set as the new underlying data & the
+    /// second is returned.
+    ///
+    /// The lock on the underlying data **is held** whilst the given function is executing, so the
+    /// returned value is derived from the data that was replaced.
+    ///
+    /// See [`Da::mutate_returning()`] for more information.
+    pub fn mutate_returning<Func, Output>(&self, func: Func) -> Output
+    where
+        Value: Clone,
+        Func: FnOnce(Option<Value>) -> (Option<Value>, Output),
+    {
+        let data = acquire_lock(&self.current_ref);
+        let (new_value, output) = func(data.as_deref().cloned());
+        self.replace_and_notify(data, new_value.map(Arc::new));
+        output
+    }
 
-            assert_eq!(*initial_content, DummyData::default());
-            assert_eq!(*data_access.get(), mutated_dummy);
+    /// Returns the given default if there is no underlying data, otherwise calls the given function
+    /// with a reference to the underlying data & returns the result.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
+    pub fn map_or<U, Func>(&self, default: U, func: Func) -> U
+    where
+        Func: FnOnce(&Value) -> U,
+    {
+        match self.get() {
+            Some(value) => func(&value),
+            None => default,
         }
+    }
 
-        #[test]
-        fn concurrency() {
-            let data_access = get_default();
-            let data_access_clone = data_access.clone();
+    /// Calls the `default` function if there is no underlying data, otherwise calls the given function
+    /// with a reference to the underlying data. The result of whichever function was called is returned.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst either function is executing.
+    pub fn map_or_else<U, DefaultFunc, Func>(&self, default: DefaultFunc, func: Func) -> U
+    where
+        DefaultFunc: FnOnce() -> U,
+        Func: FnOnce(&Value) -> U,
+    {
+        match self.get() {
+            Some(value) => func(&value),
+            None => default(),
+        }
+    }
+
+    /// Returns a reference to the underlying data if there is any, otherwise returns the result of
+    /// the given function.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
+    pub fn or_else<Func>(&self, func: Func) -> Option<Arc<Value>>
+    where
+        Func: FnOnce() -> Option<Arc<Value>>,
+    {
+        self.get().or_else(func)
+    }
+
+    /// Returns a reference to the underlying data if there is any, otherwise returns the result of
+    /// the given function.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
+    pub fn unwrap_or_else<Func>(&self, func: Func) -> Arc<Value>
+    where
+        Func: FnOnce() -> Arc<Value>,
+    {
+        self.get().unwrap_or_else(func)
+    }
+
+    /// Returns a reference to the underlying data if there is any, otherwise returns a new
+    /// reference to the result of the given function.
+    ///
+    /// The result **is not** set as the underlying data, so this [`Oda`] remains empty.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
+    pub fn get_or_else<Func>(&self, func: Func) -> Arc<Value>
+    where
+        Func: FnOnce() -> Value,
+    {
+        self.get().unwrap_or_else(|| Arc::new(func()))
+    }
+
+    /// Alias of [`Self::unwrap_or_else()`].
+    pub fn get_or_else_arc<Func>(&self, func: Func) -> Arc<Value>
+    where
+        Func: FnOnce() -> Arc<Value>,
+    {
+        self.unwrap_or_else(func)
+    }
+}
+
+/// [`DataAccess`](Da)
+/// ---
+///
+/// Facilitates "concurrent" reading & writing for the given value.
+///
+/// # Layout
+///
+/// This can't be `#[repr(transparent)]`, as it contains both the shared lock & the shared
+/// callbacks, so its layout isn't guaranteed. See [`Self::as_raw_ptr()`] to identify the shared
+/// state, and the [module documentation](self) for how the value is stored.
+///
+/// # Unwind safety
+///
+/// With the [`std::sync::Mutex`], this is [`RefUnwindSafe`](core::panic::RefUnwindSafe), as a
+/// panic whilst holding the lock poisons it. The spinlock & `parking_lot` lock can't be poisoned,
+/// so a panic within [`Self::mutate_in_place()`], [`Self::write_with()`] or an assignment operator
+/// may leave the data partially modified. So with those locks it isn't unwind safe.
+pub struct Da<Value>
+where
+    Value: 'static,
+{
+    /// Contains the data being represented.
+    /// ---
+    ///
+    /// The innermost `Value` is surrounded by an [`Arc`], so that it lives on the heap & can outlive
+    /// the reference to it from this struct.
+    ///
+    /// With the [`Mutex`] being wrapped in an [`Arc`] to allow multiple instances to point to same
+    /// data, as it will be stored on the heap.
+    pub(super) current_ref: Arc<Mutex<Arc<Value>>>,
+    /// The callbacks registered through [`Da::on_set()`], shared between clones.
+    callbacks: LazyCallbacks<Arc<Value>>,
+}
+
+impl<Value> Da<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`Da<Value>`].
+    pub fn new(data: Value) -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(Arc::new(data))),
+            callbacks: Default::default(),
+        }
+    }
+
+    /// Creates a new [`Da<Value>`] containing the value returned by the given function.
+    ///
+    /// The function is called immediately, so this is equivalent to `Da::new(func())`. See
+    /// [`LazyDa`](lazy::LazyDa) to call it on first access instead.
+    pub fn from_fn<Func>(func: Func) -> Self
+    where
+        Func: FnOnce() -> Value,
+    {
+        Self::new(func())
+    }
+
+    /// Creates a new [`Oda<Value>`] which references the given [`Arc`].
+    pub fn acquire(value_reference: Arc<Value>) -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(value_reference)),
+            callbacks: Default::default(),
+        }
+    }
+
+    /// Returns the address of the lock on the underlying data.
+    ///
+    /// This is shared between clones, so can be used to check whether two [`Da`]s point to the
+    /// same data, or to order the acquisition of multiple locks.
+    pub(crate) fn lock_address(&self) -> usize {
+        self.mutex_ptr() as usize
+    }
+
+    /// Returns a pointer to the lock on the underlying data.
+    ///
+    /// This is shared between clones & remains the same after the data is set. Whereas the
+    /// [`Pointer`] formatting of a [`Da`] shows the address of the current underlying data.
+    ///
+    /// See [`Self::as_raw_ptr()`], which returns the same pointer, for how it may be used.
+    pub fn mutex_ptr(&self) -> *const () {
+        Arc::as_ptr(&self.current_ref).cast()
+    }
+
+    /// Returns `true` if both [`Da`]s currently reference the same allocation of underlying data,
+    /// rather than only equal values.
+    ///
+    /// This is the case for clones, but also for separate [`Da`]s which were given the same [`Arc`]
+    /// through [`Self::acquire()`] or [`Self::set_arc()`]. See [`Self::state_shared()`] to check
+    /// whether both are clones instead.
+    pub fn ptr_equal(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.get(), &other.get())
+    }
+
+    /// Returns `true` if both [`Da`]s share the same lock, meaning one is a clone of the other.
+    ///
+    /// Clones will always reference the same underlying data, so any mutation made through one is
+    /// visible through the other.
+    pub fn state_shared(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.current_ref, &other.current_ref)
+    }
+
+    /// Creates a new [`Da`] containing a clone of the current underlying data, which doesn't share
+    /// state with this [`Da`].
+    ///
+    /// Unlike [`Clone::clone()`], setting the data of either [`Da`] won't affect the other. Any
+    /// callbacks registered through [`Self::on_set()`] aren't copied.
+    ///
+    /// ```
+    /// use cell_memory::data_access::Da;
+    ///
+    /// let da = Da::new(1);
+    ///
+    /// da.clone().set(2);
+    /// assert_eq!(da.copy_value(), 2);
+    ///
+    /// da.clone_independent().set(3);
+    /// assert_eq!(da.copy_value(), 2);
+    /// ```
+    pub fn clone_independent(&self) -> Self
+    where
+        Value: Clone,
+    {
+        Self::new(self.get_cloned())
+    }
+
+    /// Returns the amount of references to the current underlying data, including the one held by
+    /// this [`Da`] & its clones.
+    ///
+    /// The count is read under the lock without cloning the [`Arc`], unlike calling
+    /// [`Arc::strong_count()`] on the result of [`Self::get()`]. References may still be created or
+    /// dropped by other threads once this returns.
+    pub fn value_strong_count(&self) -> usize {
+        self.borrow_arc(Arc::strong_count)
+    }
+
+    /// Returns the amount of [`Da`]s sharing state with this [`Da`], including itself.
+    ///
+    /// See [`Self::state_shared()`].
+    pub fn outer_strong_count(&self) -> usize {
+        Arc::strong_count(&self.current_ref)
+    }
+
+    /// Returns a pointer to the shared state of this [`Da`], which is the same for every [`Da`]
+    /// sharing state with it.
+    ///
+    /// Only intended for identifying the shared state, such as when debugging or across FFI. The
+    /// pointer **must not** be dereferenced, as the layout of the shared state isn't guaranteed.
+    /// See [`Self::state_shared()`] to compare two [`Da`]s. This is the same pointer as
+    /// [`Self::mutex_ptr()`].
+    pub fn as_raw_ptr(&self) -> *const () {
+        self.mutex_ptr()
+    }
+
+    /// Converts this [`Da`] into an iterator which yields the underlying data exactly once.
+    ///
+    /// Allows a [`Da`] to be used with iterator combinators, such as [`Iterator::chain()`], without
+    /// collecting it into an intermediate collection.
+    pub fn into_iter_once(self) -> DaOnceIter<Value> {
+        DaOnceIter {
+            data_access: Some(self),
+        }
+    }
+
+    /// Converts this [`Da`] into a [`DaHistory`], which keeps up to the given amount of previous
+    /// values.
+    ///
+    /// Only changes made through the [`DaHistory`] are recorded, so any remaining clones of this
+    /// [`Da`] shouldn't be used to change the data.
+    pub fn history(self, capacity: usize) -> DaHistory<Value> {
+        DaHistory::new_from(self, capacity)
+    }
+
+    /// Attempts to take exclusive ownership of the underlying data, allowing it to be mutated in
+    /// place without being cloned.
+    ///
+    /// This only succeeds if this [`Da`] has no clones & no other references to the underlying data
+    /// exist, such as those returned by [`Self::get()`]. Otherwise, this [`Da`] is returned
+    /// unchanged.
+    ///
+    /// The data can be wrapped back into a [`Da`] through [`UniqueValue::into_da()`].
+    pub fn try_into_unique(self) -> Result<UniqueValue<Value>, Da<Value>> {
+        let Self {
+            current_ref,
+            callbacks,
+        } = self;
+
+        let mutex = match Arc::try_unwrap(current_ref) {
+            Ok(mutex) => mutex,
+            Err(current_ref) => {
+                return Err(Self {
+                    current_ref,
+                    callbacks,
+                })
+            }
+        };
+
+        match Arc::try_unwrap(sync::into_inner(mutex)) {
+            Ok(value) => Ok(UniqueValue { value, callbacks }),
+            Err(value_reference) => Err(Self {
+                current_ref: Arc::new(Mutex::new(value_reference)),
+                callbacks,
+            }),
+        }
+    }
+
+    /// Creates a new [`Da<Value>`] by parsing the given string.
+    ///
+    /// Any error from parsing is returned, rather than a [`Da`] being created.
+    pub fn parse<Str>(string: Str) -> Result<Self, Value::Err>
+    where
+        Value: FromStr,
+        Str: AsRef<str>,
+    {
+        string.as_ref().parse().map(Self::new)
+    }
+
+    /// Creates a new [`Da<Value>`] by converting the given input.
+    ///
+    /// Any error from converting is returned, rather than a [`Da`] being created.
+    ///
+    /// [`TryFrom`] can't be implemented for this, as it would conflict with the blanket
+    /// implementation of [`TryFrom`] for any type implementing [`From`].
+    pub fn try_from_value<Input>(input: Input) -> Result<Self, Value::Error>
+    where
+        Value: TryFrom<Input>,
+    {
+        Value::try_from(input).map(Self::new)
+    }
+
+    /// Creates a new [`Da`] by converting a clone of the underlying data.
+    ///
+    /// The new [`Da`] doesn't share any state with this one. Any error from converting is returned,
+    /// rather than a [`Da`] being created.
+    pub fn try_convert<Output>(&self) -> Result<Da<Output>, Output::Error>
+    where
+        Value: Clone,
+        Output: TryFrom<Value>,
+    {
+        Output::try_from(self.get_cloned()).map(Da::new)
+    }
+
+    /// Gets a reference to the current underlying data.
+    ///
+    /// This reference **will be uneffected** by any subsequent mutations.
+    pub fn get(&self) -> Arc<Value> {
+        self.try_get().expect(POISONED_MESSAGE)
+    }
+
+    /// Gets a reference to the current underlying data, returning an error if the lock is poisoned.
+    ///
+    /// See [`Self::get()`] for more information.
+    pub fn try_get(&self) -> Result<Arc<Value>, PoisonedLock<'_, Arc<Value>>> {
+        try_acquire_lock(&self.current_ref)
+            .map(|data| data.clone())
+            .map_err(PoisonedLock::new)
+    }
+
+    /// Gets a reference to the current underlying data, panicking with the given message if the
+    /// lock is poisoned.
+    ///
+    /// See [`Self::get()`] for more information.
+    #[track_caller]
+    pub fn expect_unlocked(&self, msg: &str) -> Arc<Value> {
+        self.try_get().expect(msg)
+    }
+
+    /// Acquires the lock on the underlying data, returning a guard which provides read access to it.
+    /// The lock is held until the guard is dropped.
+    ///
+    /// Unlike [`Self::get()`], which releases the lock immediately, any other attempt to access the
+    /// underlying data will wait until the guard is dropped. So the guard should be kept short-lived.
+    pub fn lock_guard(&self) -> DaGuard<'_, Value> {
+        DaGuard {
+            guard: acquire_lock(&self.current_ref),
+        }
+    }
+
+    /// Returns a guard which dereferences to the element at the given index of the current
+    /// underlying data, such as an item of a [`Vec`].
+    ///
+    /// The guard holds a reference to the data rather than the lock, see [`DaIndexed`].
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds, in the same way as indexing the data directly.
+    #[track_caller]
+    #[doc(alias = "index")]
+    pub fn index_guard<Idx>(&self, index: Idx) -> DaIndexed<Value, Idx>
+    where
+        Value: Index<Idx>,
+        Idx: Clone,
+    {
+        let data = self.get();
+        // Checks the index now, rather than panicking when dereferenced.
+        let _ = &data[index.clone()];
+        DaIndexed { data, index }
+    }
+
+    /// Calls the given function with a reference to the underlying data, returning [`Self`] to
+    /// allow for chaining.
+    ///
+    /// The lock on the underlying data is only held whilst the function is executing.
+    pub fn inspect<Func>(&self, func: Func) -> &Self
+    where
+        Func: FnOnce(&Value),
+    {
+        func(&acquire_lock(&self.current_ref));
+        self
+    }
+
+    /// Calls the given function with a reference to the underlying data, returning the result.
+    ///
+    /// The lock on the underlying data is held whilst the function is executing, with the
+    /// reference being unable to escape the function.
+    ///
+    /// [`AsRef<Value>`] & [`Borrow<Value>`](std::borrow::Borrow) aren't implemented, as they would
+    /// have to return a reference that outlives the lock, allowing the data to be replaced whilst
+    /// it's borrowed.
+    /// Use this method, or [`Self::lock_guard()`] if the reference needs to be held for longer.
+    pub fn as_ref_with<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(&Value) -> Output,
+    {
+        func(&acquire_lock(&self.current_ref))
+    }
+
+    /// Alias of [`Self::as_ref_with()`].
+    pub fn borrow_current<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(&Value) -> Output,
+    {
+        self.as_ref_with(func)
+    }
+
+    /// Calls the given function with a reference to the [`Arc`] of the underlying data, returning
+    /// the result.
+    ///
+    /// The lock is held until the function returns. Unlike [`Self::get()`], the [`Arc`] isn't
+    /// cloned, which avoids updating its reference count for frequent reads. The [`Arc`] can still
+    /// be cloned within the function if a reference needs to outlive the lock.
+    pub fn borrow_arc<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(&Arc<Value>) -> Output,
+    {
+        func(&acquire_lock(&self.current_ref))
+    }
+
+    /// Returns a weak reference to the underlying data, which doesn't keep it alive.
+    ///
+    /// Once the data has been replaced & every strong reference to it has been dropped, the weak
+    /// reference can no longer be upgraded. The [`Arc`] isn't cloned, see [`Self::borrow_arc()`].
+    pub fn get_weak(&self) -> Weak<Value> {
+        self.borrow_arc(Arc::downgrade)
+    }
+
+    /// Calls the given function with the current underlying data, returning the result.
+    ///
+    /// This allows the data to be passed into a chain of functions without an intermediate
+    /// variable. The lock isn't held whilst the function is executing.
+    ///
+    /// ```
+    /// use cell_memory::data_access::Da;
+    ///
+    /// let prices = Da::new(vec![3, 1, 2]);
+    /// let total = prices.pipe(|prices| prices.iter().sum::<u32>());
+    /// let summary = Da::new(total).pipe(|total| format!("Total: {total}"));
+    ///
+    /// assert_eq!(summary, "Total: 6");
+    /// ```
+    pub fn pipe<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(Arc<Value>) -> Output,
+    {
+        func(self.get())
+    }
+
+    /// Creates a new [`Da`] containing the [`Arc`] returned by the given function, which is called
+    /// with the current underlying data.
+    ///
+    /// The [`Arc`] is passed directly to the function, so the data doesn't have to implement
+    /// [`Clone`]. The returned [`Da`] is a snapshot, which isn't updated when this [`Da`] changes.
+    /// The lock isn't held whilst the function is executing.
+    pub fn map_arc<Output, Func>(&self, func: Func) -> Da<Output>
+    where
+        Output: 'static,
+        Func: FnOnce(Arc<Value>) -> Arc<Output>,
+    {
+        Da::acquire(func(self.get()))
+    }
+
+    /// Creates a live read-only view of the part of the underlying data returned by the given
+    /// function, such as a field.
+    ///
+    /// Unlike [`Self::map_arc()`], the view isn't a snapshot. The function is called with the
+    /// current underlying data each time the view is read, so changes to this [`Da`] are
+    /// immediately visible through it.
+    pub fn project<Output, Func>(&self, extractor: Func) -> ProjectedDa<Output>
+    where
+        Value: Send + Sync,
+        Output: Clone + 'static,
+        Func: Fn(&Value) -> &Output + Send + Sync + 'static,
+    {
+        ProjectedDa::new(self.clone(), extractor)
+    }
+
+    /// Calls the given function with a reference to the underlying data, returning the result.
+    ///
+    /// The same as [`Self::pipe()`], except that the [`Arc`] isn't cloned, so the lock is held
+    /// whilst the function is executing. See [`Self::as_ref_with()`].
+    pub fn pipe_with<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(&Value) -> Output,
+    {
+        self.as_ref_with(func)
+    }
+
+    /// Allows for a value that implements [`Copy`] to be copied out of [`Da`].
+    ///
+    /// This copy is in no way related to the underlying data other than by it's value of the time
+    /// of copying.
+    pub fn copy_value(&self) -> Value
+    where
+        Value: Copy,
+    {
+        **acquire_lock(&self.current_ref)
+    }
+
+    /// Sets the underlying data to the given value, returning a copy of the old value.
+    ///
+    /// The lock is held whilst copying & setting, so no other mutation can occur in-between. This
+    /// is the equivalent of [`core::mem::replace()`].
+    pub fn copy_and_set(&self, new_data: Value) -> Value
+    where
+        Value: Copy,
+    {
+        let data = acquire_lock(&self.current_ref);
+        let old_value = **data;
+        self.replace_and_notify(data, Arc::new(new_data));
+        old_value
+    }
+
+    /// Clones the underlying data out of the [`Da`].
+    ///
+    /// The clone is fully independent of the [`Da`], so won't be affected by any subsequent
+    /// mutations. This is equivalent to `(*da.get()).clone()`.
+    pub fn get_cloned(&self) -> Value
+    where
+        Value: Clone,
+    {
+        (*self.get()).clone()
+    }
+
+    /// Clears the poison from the lock on the underlying data, if a thread panicked whilst holding it.
+    /// Returns `true` if the lock was poisoned & has been recovered, or `false` if the lock was healthy.
+    ///
+    /// The underlying data is left as it was when the panicking thread released the lock.
+    pub fn recover_from_poison(&self) -> bool {
+        match sync::lock(&self.current_ref) {
+            Ok(_) => false,
+            Err(poisoned) => {
+                drop(poisoned.into_inner());
+                sync::clear_poison(&self.current_ref);
+                true
+            }
+        }
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value. All references
+    /// to the old data will continue to remain valid.
+    ///
+    /// Any subsequent calls to [`get`](Self::get()) will return the new data.
+    ///
+    /// Any existing references from [`get`](Self::get()) will remain pointing to the old data.
+    pub fn set(&self, new_data: Value) -> Arc<Value> {
+        self.try_set(new_data).expect(POISONED_MESSAGE)
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value, or an error if
+    /// the lock is poisoned.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn try_set(&self, new_data: Value) -> Result<Arc<Value>, PoisonedLock<'_, Arc<Value>>> {
+        let data = try_acquire_lock(&self.current_ref).map_err(PoisonedLock::new)?;
+        Ok(self.replace_and_notify(data, Arc::new(new_data)))
+    }
+
+    /// Gets a reference to the current underlying data, or `None` if the lock couldn't be acquired
+    /// within the given timeout.
+    ///
+    /// See [`Self::get()`] for more information.
+    #[cfg(feature = "parking_lot")]
+    pub fn get_timeout(&self, timeout: Duration) -> Option<Arc<Value>> {
+        sync::lock_timeout(&self.current_ref, timeout).map(|data| data.clone())
+    }
+
+    /// Creates new underlying data with the given value, returning `false` if the lock couldn't be
+    /// acquired within the given timeout.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    #[cfg(feature = "parking_lot")]
+    pub fn set_timeout(&self, new_data: Value, timeout: Duration) -> bool {
+        match sync::lock_timeout(&self.current_ref, timeout) {
+            Some(data) => {
+                self.replace_and_notify(data, Arc::new(new_data));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the underlying data to the given value, only if it differs from the current value.
+    /// Returns `true` if the underlying data was changed.
+    ///
+    /// The lock is held whilst comparing, so no other mutation can occur in-between. If the values
+    /// are equal, the existing data is kept & no new allocation is made.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn conditional_set(&self, new_data: Value) -> bool
+    where
+        Value: PartialEq,
+    {
+        let data = acquire_lock(&self.current_ref);
+        if **data == new_data {
+            return false;
+        }
+
+        self.replace_and_notify(data, Arc::new(new_data));
+        true
+    }
+
+    /// Sets the underlying data to the given [`Arc`]; Returning the old value.
+    ///
+    /// Unlike [`Self::set()`], which always allocates a new [`Arc`] for the given value, the given
+    /// [`Arc`] is stored as-is. So any existing references to it, such as one returned by
+    /// [`Self::get()`] on another [`Da`], will point to the same data as this [`Da`].
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn set_arc(&self, data_arc: Arc<Value>) -> Arc<Value> {
+        self.replace_and_notify(acquire_lock(&self.current_ref), data_arc)
+    }
+
+    /// Replaces the the [`Arc`] contained within [`Self`] to the given [`Arc`]. The given [`Arc`] is
+    /// held via a strong reference.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    #[deprecated(note = "Renamed to `set_arc`, to match `set`")]
+    pub fn replace(&self, data_arc: Arc<Value>) {
+        self.set_arc(data_arc);
+    }
+
+    /// Registers the given callback, which is called with the new underlying data whenever it's
+    /// changed. The callback is shared between clones of this [`Da`].
+    ///
+    /// Every method which changes the underlying data calls the callbacks, including those which
+    /// modify it in place, such as [`Self::write_with()`] & the assignment operators. The only
+    /// exception is [`locking_mutate!`](crate::locking_mutate!), which writes through the lock
+    /// directly, as does writing through the [`Mutex`] from [`AsRef`].
+    ///
+    /// The callbacks are called after the lock on the underlying data has been released, so a
+    /// callback may read the [`Da`], as well as register or deregister callbacks. However, it
+    /// mustn't change the same [`Da`], as the callback would be called again whilst it's still
+    /// executing, which deadlocks. If a callback panics, the panic is propagated from the method
+    /// which made the change, after the change has been made.
+    ///
+    /// The callback is deregistered when the returned [`CallbackHandle`] is dropped.
+    pub fn on_set<Func>(&self, callback: Func) -> CallbackHandle
+    where
+        Func: Fn(Arc<Value>) + Send + 'static,
+    {
+        callbacks::register(&self.callbacks, callback)
+    }
+
+    /// Replaces the underlying data, then releases the given lock & calls the
+    /// [`on_set`](Self::on_set()) callbacks; Returning the old value.
+    ///
+    /// Every method which replaces the underlying data of a single [`Da`] goes through this, so
+    /// the callbacks observe every change.
+    fn replace_and_notify(
+        &self,
+        mut data: MutexGuard<'_, Arc<Value>>,
+        new_data: Arc<Value>,
+    ) -> Arc<Value> {
+        let old_data = core::mem::replace(&mut *data, new_data);
+        self.release_and_notify(data);
+        old_data
+    }
+
+    /// Releases the given lock, then calls the [`on_set`](Self::on_set()) callbacks with the
+    /// underlying data. Used directly after modifying the underlying data in place.
+    fn release_and_notify(&self, data: MutexGuard<'_, Arc<Value>>) {
+        let new_data = Arc::clone(&data);
+        drop(data);
+        callbacks::notify(&self.callbacks, new_data);
+    }
+
+    /// Returns a new [`Da`] containing the given initial value, which is updated by calling the
+    /// given function with it & the new underlying data, whenever this [`Da`] is changed.
+    ///
+    /// This allows for deriving an aggregate, such as a running sum, from the values this [`Da`]
+    /// has held. The function is called through [`Self::on_set()`], so every change other than
+    /// through [`locking_mutate!`](crate::locking_mutate!) updates the aggregate & the same
+    /// restrictions apply. The current underlying data isn't included.
+    ///
+    /// The lock on the returned [`Da`] is held whilst the function is executing, so changes made
+    /// to it directly aren't lost.
+    ///
+    /// The returned [`Da`] doesn't keep the callback alive. Once it & its clones are dropped, the
+    /// callback is deregistered the next time this [`Da`] is changed.
+    pub fn scan<Acc, Func>(&self, initial: Acc, func: Func) -> Da<Acc>
+    where
+        Acc: Clone + Send + Sync + 'static,
+        Func: Fn(Acc, &Value) -> Acc + Send + 'static,
+    {
+        let scanned = Da::new(initial);
+
+        // Held weakly, so the callback doesn't keep the returned `Da` alive.
+        let current_ref = Arc::downgrade(&scanned.current_ref);
+        let acc_callbacks = Arc::downgrade(scanned.callbacks.shared());
+        callbacks::register_while(&self.callbacks, move |new_data: Arc<Value>| {
+            let (Some(current_ref), Some(callbacks)) =
+                (current_ref.upgrade(), acc_callbacks.upgrade())
+            else {
+                return false;
+            };
+
+            let scanned = Da {
+                current_ref,
+                callbacks: callbacks.into(),
+            };
+            // The lock is held whilst updating, so concurrent changes to the aggregate aren't lost.
+            scanned.mutate_returning(|acc| (func(acc, &new_data), ()));
+            true
+        });
+
+        scanned
+    }
+
+    /// Sets the underlying data of every given [`Da`] to the given value, whilst holding all of
+    /// their locks. So no [`Da`] can be observed with the new value whilst another still has its
+    /// old value, through methods that acquire the lock.
+    ///
+    /// The locks are acquired in order of their addresses, so concurrent calls can't deadlock.
+    /// Clones of the same [`Da`] may be given, in which case it's only set once. Every [`Da`]
+    /// references the same new [`Arc`].
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn fanout(targets: &[&Da<Value>], new_data: Value) {
+        let new_data = Arc::new(new_data);
+        let targets = sorted_by_lock(targets, Self::lock_address);
+
+        let mut locks: Vec<_> = targets
+            .iter()
+            .map(|target| acquire_lock(&target.current_ref))
+            .collect();
+        for data in locks.iter_mut() {
+            **data = new_data.clone();
+        }
+        // Every lock must be released before any callback is called, so this can't go through
+        // `replace_and_notify()`.
+        drop(locks);
+
+        for target in targets {
+            callbacks::notify(&target.callbacks, new_data.clone());
+        }
+    }
+
+    /// Takes the value out of the [`Da`], leaving [`Value::default()`](Default::default()) in its place.
+    ///
+    /// The lock on the underlying data is held whilst the value is being swapped, so no other
+    /// mutation can occur between reading the current value & resetting it.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn take(&self) -> Arc<Value>
+    where
+        Value: Default,
+    {
+        self.replace_and_notify(acquire_lock(&self.current_ref), Arc::new(Value::default()))
+    }
+
+    /// Exchanges the underlying data of this [`Da`] with the underlying data of the other [`Da`].
+    ///
+    /// Both locks are held whilst the data is being exchanged, so no other mutation can occur
+    /// in-between. If both [`Da`]s point to the same data, this method has no effect.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn swap_with(&self, other: &Da<Value>) {
+        if let Some((mut data, mut other_data)) =
+            acquire_ordered_locks(&self.current_ref, &other.current_ref)
+        {
+            core::mem::swap(&mut *data, &mut *other_data);
+
+            // Both locks must be released before either callback is called.
+            let (new_data, other_new_data) = (data.clone(), other_data.clone());
+            drop((data, other_data));
+            callbacks::notify(&self.callbacks, new_data);
+            callbacks::notify(&other.callbacks, other_new_data);
+        }
+    }
+
+    /// Sets the underlying data to the value returned by the given function, which is called with
+    /// clones of the underlying data of both [`Da`]s. The other [`Da`] is left unmodified.
+    ///
+    /// The locks on both [`Da`]s are held whilst the function is executing. The locks are acquired
+    /// in a consistent order, so calling this from different threads can't deadlock.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn merge_with<Func>(&self, other: &Da<Value>, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value, Value) -> Value,
+    {
+        match acquire_ordered_locks(&self.current_ref, &other.current_ref) {
+            Some((data, other_data)) => {
+                let new_data = Arc::new(func(data.ooa(), other_data.ooa()));
+                drop(other_data);
+                self.replace_and_notify(data, new_data);
+            }
+            // Both point to the same data, so it's merged with itself.
+            None => {
+                let data = acquire_lock(&self.current_ref);
+                let new_data = Arc::new(func(data.ooa(), data.ooa()));
+                self.replace_and_notify(data, new_data);
+            }
+        }
+    }
+
+    /// Creates a new [`Da`] from the value returned by the given function, which is called with the
+    /// underlying data of both [`Da`]s.
+    ///
+    /// The locks on both [`Da`]s are held whilst the function is executing, so the values are read
+    /// at the same point in time. The locks are acquired in a consistent order, so calling this
+    /// from different threads can't deadlock.
+    ///
+    /// The returned [`Da`] **is not** updated by any subsequent mutations to either [`Da`].
+    pub fn zip_with<Other, Output, Func>(&self, other: &Da<Other>, func: Func) -> Da<Output>
+    where
+        Func: FnOnce(&Value, &Other) -> Output,
+    {
+        if self.lock_address() == other.lock_address() {
+            let data = acquire_lock(&self.current_ref);
+            let other_data = (&**data as &dyn Any)
+                .downcast_ref()
+                .expect(SHARED_LOCK_TYPE_MESSAGE);
+            return Da::new(func(&data, other_data));
+        }
+
+        let (data, other_data) = match self.lock_address() < other.lock_address() {
+            true => {
+                let data = acquire_lock(&self.current_ref);
+                (data, acquire_lock(&other.current_ref))
+            }
+            false => {
+                let other_data = acquire_lock(&other.current_ref);
+                (acquire_lock(&self.current_ref), other_data)
+            }
+        };
+
+        Da::new(func(&data, &other_data))
+    }
+
+    /// Sets the underlying data to the value returned from the given function, returning the
+    /// **old** value.
+    ///
+    /// The lock on the underlying data is held whilst the function is executing, so the returned
+    /// value is guaranteed to be the value the function was called with.
+    fn fetch_update<Func>(&self, func: Func) -> Value
+    where
+        Value: Copy,
+        Func: FnOnce(Value) -> Value,
+    {
+        let data = acquire_lock(&self.current_ref);
+        let old_value = **data;
+        self.replace_and_notify(data, Arc::new(func(old_value)));
+        old_value
+    }
+
+    /// Atomically adds the given value to the underlying data, returning the **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_add()`](std::sync::atomic::AtomicUsize::fetch_add()) for the
+    /// comparable atomic operation, which wraps on overflow rather than panicking.
+    ///
+    /// # Panics
+    ///
+    /// If the addition overflows in a debug build, the same as the operator. The lock is held
+    /// whilst the new value is calculated, so this also poisons the lock, unless the lock can't be
+    /// poisoned. See [`Self::recover_from_poison()`].
+    pub fn fetch_add(&self, value: Value) -> Value
+    where
+        Value: Copy + Add<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value + value)
+    }
+
+    /// Atomically subtracts the given value from the underlying data, returning the **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_sub()`](std::sync::atomic::AtomicUsize::fetch_sub()) for the
+    /// comparable atomic operation, which wraps on overflow rather than panicking.
+    ///
+    /// # Panics
+    ///
+    /// If the subtraction overflows in a debug build, the same as the operator. The lock is held
+    /// whilst the new value is calculated, so this also poisons the lock, unless the lock can't be
+    /// poisoned. See [`Self::recover_from_poison()`].
+    pub fn fetch_sub(&self, value: Value) -> Value
+    where
+        Value: Copy + Sub<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value - value)
+    }
+
+    /// Atomically multiplies the given value with the underlying data, returning the **old** value.
+    ///
+    /// # Panics
+    ///
+    /// If the multiplication overflows in a debug build, the same as the operator. The lock is held
+    /// whilst the new value is calculated, so this also poisons the lock, unless the lock can't be
+    /// poisoned. See [`Self::recover_from_poison()`].
+    pub fn fetch_mul(&self, value: Value) -> Value
+    where
+        Value: Copy + Mul<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value * value)
+    }
+
+    /// Atomically performs a bitwise "and" of the given value with the underlying data, returning the **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_and()`](std::sync::atomic::AtomicUsize::fetch_and()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_and(&self, value: Value) -> Value
+    where
+        Value: Copy + BitAnd<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value & value)
+    }
+
+    /// Atomically performs a bitwise "or" of the given value with the underlying data, returning the **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_or()`](std::sync::atomic::AtomicUsize::fetch_or()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_or(&self, value: Value) -> Value
+    where
+        Value: Copy + BitOr<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value | value)
+    }
+
+    /// Atomically performs a bitwise "xor" of the given value with the underlying data, returning the **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_xor()`](std::sync::atomic::AtomicUsize::fetch_xor()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_xor(&self, value: Value) -> Value
+    where
+        Value: Copy + BitXor<Output = Value>,
+    {
+        self.fetch_update(|old_value| old_value ^ value)
+    }
+
+    /// Atomically sets the underlying data to the maximum of it & the given value, returning the
+    /// **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_max()`](std::sync::atomic::AtomicUsize::fetch_max()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_max(&self, value: Value) -> Value
+    where
+        Value: Copy + Ord,
+    {
+        self.fetch_update(|old_value| old_value.max(value))
+    }
+
+    /// Atomically sets the underlying data to the minimum of it & the given value, returning the
+    /// **old** value.
+    ///
+    /// See [`AtomicUsize::fetch_min()`](std::sync::atomic::AtomicUsize::fetch_min()) for the
+    /// equivalent atomic operation.
+    pub fn fetch_min(&self, value: Value) -> Value
+    where
+        Value: Copy + Ord,
+    {
+        self.fetch_update(|old_value| old_value.min(value))
+    }
+
+    /// Atomically inverts the underlying data, such as flipping a `bool`.
+    ///
+    /// The lock is held whilst inverting, so concurrent toggles are never lost.
+    pub fn toggle(&self)
+    where
+        Value: Copy + Not<Output = Value>,
+    {
+        self.fetch_update(|value| !value);
+    }
+
+    /// Clones the existing underlying data & calls the given function with the clone as the parameter.
+    /// The value returned from the function will be set as the new underlying data.
+    ///
+    /// This method **does not** hold a lock on the underlying data whilst the given function is executing.
+    /// It only waits to acquire a lock when it's reading the value for the initial clone & when writing the
+    /// mutated value back to the [`Da`].
+    /// Due to cloning the data out of the [`Da`], the value passed into the function **is immutable**.
+    ///
+    /// See [`locking_mutate!()`](crate::data_access::locking_mutate::locking_mutate) if you want a persistent lock.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        self.try_mutate(func).expect(POISONED_MESSAGE)
+    }
+
+    /// The same as [`Self::mutate()`], but returns an error if the lock is poisoned.
+    pub fn try_mutate<Func>(&self, func: Func) -> Result<(), PoisonedLock<'_, Arc<Value>>>
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        let mutated_value = func((*self.try_get()?).clone());
+        self.try_set(mutated_value)?;
+        Ok(())
+    }
+
+    /// Clones the existing underlying data & calls the given function with the clone as the parameter.
+    /// The value returned from the function will be set as the new underlying data. Returns the
+    /// data from before & after the mutation.
+    ///
+    /// Unlike [`Self::mutate()`], the lock on the underlying data **is held** whilst the given
+    /// function is executing, so no other mutation can occur between the returned values.
+    ///
+    /// See [`Self::set()`] for more information on the behaviour of current & future references.
+    pub fn mutate_with_previous<Func>(&self, func: Func) -> (Arc<Value>, Arc<Value>)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        let data = acquire_lock(&self.current_ref);
+        let new_data = Arc::new(func((**data).clone()));
+        let old_data = self.replace_and_notify(data, new_data.clone());
+        (old_data, new_data)
+    }
+
+    /// Calls the given function with a clone of the underlying data. The first value returned from
+    /// the function is set as the new underlying data & the second is returned.
+    ///
+    /// Unlike [`Self::mutate_with_previous()`], the returned value can be anything derived whilst
+    /// mutating, such as a list of the changes made. The lock on the underlying data **is held**
+    /// whilst the given function is executing, so no other mutation can occur in-between. The
+    /// [`on_set`](Self::on_set()) callbacks are called once the lock is released.
+    ///
+    /// ```
+    /// # use cell_memory::data_access::Da;
+    /// let queue = Da::new(vec![1, 2, 3]);
+    ///
+    /// let first = queue.mutate_returning(|mut queue| {
+    ///     let first = queue.remove(0);
+    ///     (queue, first)
+    /// });
+    ///
+    /// assert_eq!(first, 1);
+    /// assert_eq!(*queue.get(), [2, 3]);
+    /// ```
+    pub fn mutate_returning<Func, Output>(&self, func: Func) -> Output
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> (Value, Output),
+    {
+        let data = acquire_lock(&self.current_ref);
+        let (new_value, output) = func((**data).clone());
+        self.replace_and_notify(data, Arc::new(new_value));
+        output
+    }
+
+    /// Holds the lock on the underlying data whilst calling the given function with a mutable
+    /// reference to it, returning the function's output.
+    ///
+    /// If there are any existing references to the underlying data, it's cloned so that they remain
+    /// unaffected, otherwise it's modified in place. This avoids the clone always made by
+    /// [`Self::mutate()`]. See the `collection_helpers` benchmark.
+    pub fn write_with<Func, Output>(&self, func: Func) -> Output
+    where
+        Value: Clone,
+        Func: FnOnce(&mut Value) -> Output,
+    {
+        let mut data = acquire_lock(&self.current_ref);
+        let output = func(Arc::make_mut(&mut data));
+        self.release_and_notify(data);
+        output
+    }
+
+    /// Calls the given function with a mutable reference to the underlying data, whilst holding the
+    /// lock on it.
+    ///
+    /// When there are no existing references to the underlying data, it's modified in place rather
+    /// than being cloned into a new allocation like [`Self::mutate()`]. See the `mutation`
+    /// benchmark. See [`Self::write_with()`] to return a value from the function.
+    #[doc(alias = "map_in_place_or_clone", alias = "make_mut")]
+    pub fn mutate_in_place<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(&mut Value),
+    {
+        self.write_with(func);
+    }
+
+    /// Alias of [`Self::mutate_in_place()`].
+    pub fn mutate_efficient<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(&mut Value),
+    {
+        self.mutate_in_place(func);
+    }
+}
+
+impl<Value> Oda<Option<Value>>
+where
+    Value: Clone + 'static,
+{
+    /// Converts an [`Oda`] of an [`Option`] into an [`Option`] of an [`Oda`].
+    ///
+    /// No data maps to `None`, `Some(None)` maps to `Some` of an empty [`Oda`] & `Some(Some(value))`
+    /// maps to `Some` of an [`Oda`] containing the value. The value is cloned, as it may be
+    /// referenced elsewhere.
+    ///
+    /// See [`Option::transpose()`] for more information.
+    pub fn transpose(self) -> Option<Oda<Value>> {
+        match self.get().as_deref() {
+            None => None,
+            Some(None) => Some(Oda::default()),
+            Some(Some(value)) => Some(Oda::new(value.clone())),
+        }
+    }
+}
+
+impl<Value, Error> Oda<Result<Value, Error>>
+where
+    Value: Clone + 'static,
+    Error: Clone + 'static,
+{
+    /// Converts an [`Oda`] of a [`Result`] into a [`Result`] of an [`Oda`].
+    ///
+    /// No data maps to `Ok` of an empty [`Oda`], `Some(Ok(value))` maps to `Ok` of an [`Oda`]
+    /// containing the value & `Some(Err(error))` maps to `Err(error)`. The value or error is
+    /// cloned, as it may be referenced elsewhere.
+    ///
+    /// See [`Result::transpose()`] for more information.
+    pub fn transpose(self) -> Result<Oda<Value>, Error> {
+        match self.get().as_deref() {
+            None => Ok(Oda::default()),
+            Some(Ok(value)) => Ok(Oda::new(value.clone())),
+            Some(Err(error)) => Err(error.clone()),
+        }
+    }
+}
+
+impl<Item> Da<Vec<Item>>
+where
+    Item: Clone + 'static,
+{
+    /// Appends the given item to the end of the underlying [`Vec`].
+    ///
+    /// The lock is held whilst appending. If there are any existing references to the underlying
+    /// data, the [`Vec`] is cloned so that they remain unaffected, otherwise it's modified in place.
+    ///
+    /// Modifying in place avoids the clone made by [`Self::mutate()`], which makes this
+    /// significantly faster for large collections. See the `collection_helpers` benchmark.
+    pub fn push_into(&self, item: Item) {
+        self.write_with(|data| data.push(item));
+    }
+
+    /// Removes the last item from the underlying [`Vec`] & returns it, or `None` if it's empty.
+    ///
+    /// See [`Self::push_into()`] for when the [`Vec`] is cloned.
+    pub fn pop_from(&self) -> Option<Item> {
+        let mut data = acquire_lock(&self.current_ref);
+        if data.is_empty() {
+            return None;
+        }
+
+        let item = Arc::make_mut(&mut data).pop();
+        self.release_and_notify(data);
+        item
+    }
+}
+
+impl Da<String> {
+    /// Appends the given string slice to the end of the underlying [`String`].
+    ///
+    /// See [`Da::push_into()`] for when the [`String`] is cloned.
+    pub fn push_str(&self, string: &str) {
+        self.write_with(|data| data.push_str(string));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Key, Value> Da<HashMap<Key, Value>>
+where
+    Key: Clone + Eq + core::hash::Hash + 'static,
+    Value: Clone + 'static,
+{
+    /// Inserts the given key-value pair into the underlying [`HashMap`], returning the previous
+    /// value for the key.
+    ///
+    /// See [`Da::push_into()`] for when the [`HashMap`] is cloned.
+    pub fn insert_entry(&self, key: Key, value: Value) -> Option<Value> {
+        self.write_with(|data| data.insert(key, value))
+    }
+}
+
+impl<Value> PartialEq for Oda<Value>
+where
+    Value: PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self.get(), other.get()) {
+            (None, None) => true,
+            (None, Some(_)) | (Some(_), None) => false,
+            (Some(self_data), Some(other_data)) => *self_data == *other_data,
+        }
+    }
+}
+
+impl<Value> Eq for Oda<Value> where Value: Eq + 'static {}
+
+impl<Value> Hash for Oda<Value>
+where
+    Value: Hash + 'static,
+{
+    /// Hashes the underlying data, with no data hashing differently to any value.
+    ///
+    /// See the [`Hash`] implementation for [`Da`] for the dangers of using an [`Oda`] as a key.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        acquire_lock(&self.current_ref).as_deref().hash(state);
+    }
+}
+
+impl<Value> PartialEq for Da<Value>
+where
+    Value: PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.get() == *other.get()
+    }
+}
+
+impl<Value> Eq for Da<Value> where Value: Eq + 'static {}
+
+impl<Value> Hash for Da<Value>
+where
+    Value: Hash + 'static,
+{
+    /// Hashes the underlying data.
+    ///
+    /// **Warning:** As with a [`RefCell`](std::cell::RefCell), the underlying data can be changed
+    /// whilst a [`Da`] is being used as a key in a [`HashMap`] or a
+    /// [`HashSet`](std::collections::HashSet). Mutating a [`Da`] whilst it's used as a key will
+    /// cause its hash to change, resulting in unspecified behaviour from the collection.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        acquire_lock(&self.current_ref).hash(state);
+    }
+}
+
+impl<Value> Debug for Oda<Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Oda")
+            .field("current_ref", &self.get())
+            .finish()
+    }
+}
+
+impl<Value> From<Value> for Oda<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Value> From<Oda<Value>> for Option<Arc<Value>>
+where
+    Value: 'static,
+{
+    /// Returns a reference to the underlying data at the time of conversion, as with
+    /// [`Oda::get()`].
+    fn from(oda: Oda<Value>) -> Self {
+        oda.get()
+    }
+}
+
+impl<Value> From<Oda<Value>> for Option<Value>
+where
+    Value: Clone + 'static,
+{
+    /// Returns a clone of the underlying data at the time of conversion.
+    fn from(oda: Oda<Value>) -> Self {
+        oda.get().map(|value| (*value).clone())
+    }
+}
+
+impl<Value> Pointer for Oda<Value>
+where
+    Value: 'static,
+{
+    /// Formats the address of the current underlying data, or a null pointer if there is none.
+    ///
+    /// See [`Oda::mutex_ptr()`] for the address of the lock instead.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let pointer = self
+            .get()
+            .map_or(core::ptr::null(), |data| Arc::as_ptr(&data));
+        Pointer::fmt(&pointer, f)
+    }
+}
+
+impl<Value> AsRef<Arc<Mutex<Option<Arc<Value>>>>> for Oda<Value>
+where
+    Value: 'static,
+{
+    /// Returns the internal lock on the underlying data, for use with code which expects an
+    /// `Arc<Mutex<Option<Arc<Value>>>>`.
+    ///
+    /// See the implementation for [`Da`] for the caveats of this.
+    fn as_ref(&self) -> &Arc<Mutex<Option<Arc<Value>>>> {
+        &self.current_ref
+    }
+}
+
+impl<Value> Default for Oda<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new(None)`](Self::new()).
+    fn default() -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(None)),
+            callbacks: Default::default(),
+        }
+    }
+}
+
+impl<Value> Clone for Oda<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`Oda`] pointing to the **exact same** value as the original [`Oda`].
+    ///
+    /// See [`Oda::clone_independent()`] to create an [`Oda`] which doesn't share state.
+    fn clone(&self) -> Self {
+        Self {
+            current_ref: self.current_ref.clone(),
+            callbacks: self.callbacks.clone(),
+        }
+    }
+}
+
+impl<Value> Debug for Da<Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Da")
+            .field("current_ref", &self.get())
+            .finish()
+    }
+}
+
+impl<Value> Pointer for Da<Value>
+where
+    Value: 'static,
+{
+    /// Formats the address of the current underlying data.
+    ///
+    /// See [`Da::mutex_ptr()`] for the address of the lock instead.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Pointer::fmt(&Arc::as_ptr(&self.get()), f)
+    }
+}
+
+impl<Value> AsRef<Arc<Mutex<Arc<Value>>>> for Da<Value>
+where
+    Value: 'static,
+{
+    /// Returns the internal lock on the underlying data, for use with code which expects an
+    /// `Arc<Mutex<Arc<Value>>>`.
+    ///
+    /// The [`Mutex`] is the one from the [`sync`] module, so depends on the enabled features.
+    /// Setting the data through it won't call the callbacks registered through [`Da::on_set()`].
+    ///
+    /// This exposes the internal representation, so is subject to change if the lock is ever
+    /// replaced, such as by an `RwLock` or a lock-free primitive.
+    fn as_ref(&self) -> &Arc<Mutex<Arc<Value>>> {
+        &self.current_ref
+    }
+}
+
+impl<Value> Default for Da<Value>
+where
+    Value: Default + 'static,
+{
+    /// Idiomatic to calling [`Self::new(value::default())`](Self::new()).
+    fn default() -> Self {
+        Self {
+            current_ref: Arc::new(Mutex::new(Arc::new(Value::default()))),
+            callbacks: Default::default(),
+        }
+    }
+}
+
+impl<Value> From<Value> for Da<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Value> From<Da<Value>> for Arc<Value>
+where
+    Value: 'static,
+{
+    /// Returns a reference to the underlying data at the time of conversion, as with
+    /// [`Da::get()`]. As `Arc<Da<Value>>` also implements `From<Da<Value>>`, the type of the
+    /// [`Arc`] must be specified, such as `Arc::<Value>::from(da)`.
+    ///
+    /// `From<Da<Value>>` can't be implemented for `Value` itself, as the orphan rule forbids
+    /// implementing a foreign trait for an uncovered type parameter. Use
+    /// `Value::clone(&Arc::<Value>::from(da))` to clone the underlying data instead.
+    fn from(da: Da<Value>) -> Self {
+        da.get()
+    }
+}
+
+/// Implements the given assignment operator for [`Da`], by applying it to the underlying data.
+macro_rules! impl_assign_op {
+    ($($trait:ident::$method:ident),+ $(,)?) => {
+        $(
+            impl<Value, Rhs> $trait<Rhs> for Da<Value>
+            where
+                Value: $trait<Rhs> + Clone + 'static,
+            {
+                /// Applies the operator to the underlying data whilst holding the lock on it, so
+                /// concurrent assignments are never lost.
+                ///
+                /// See [`Da::mutate_in_place()`] for when the underlying data is cloned.
+                fn $method(&mut self, rhs: Rhs) {
+                    self.mutate_in_place(|data| data.$method(rhs));
+                }
+            }
+        )+
+    };
+}
+
+impl_assign_op!(
+    AddAssign::add_assign,
+    SubAssign::sub_assign,
+    MulAssign::mul_assign,
+    DivAssign::div_assign,
+    RemAssign::rem_assign,
+    BitAndAssign::bitand_assign,
+    BitOrAssign::bitor_assign,
+    BitXorAssign::bitxor_assign,
+    ShlAssign::shl_assign,
+    ShrAssign::shr_assign,
+);
+
+/// Implements the given formatting traits for [`Da`] & [`Oda`], by delegating to the underlying
+/// data.
+macro_rules! impl_fmt {
+    ($($trait:ident),+ $(,)?) => {
+        $(
+            impl<Value> core::fmt::$trait for Da<Value>
+            where
+                Value: core::fmt::$trait + 'static,
+            {
+                /// Formats the current underlying data.
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$trait::fmt(&*self.get(), f)
+                }
+            }
+
+            impl<Value> core::fmt::$trait for Oda<Value>
+            where
+                Value: core::fmt::$trait + 'static,
+            {
+                /// Formats the current underlying data, or `(none)` if there is none.
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    match self.get() {
+                        Some(value) => core::fmt::$trait::fmt(&*value, f),
+                        None => f.pad("(none)"),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt!(Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+
+/// Implements the given unary operator for references to [`Da`] & [`Oda`], by applying it to a copy
+/// of the underlying data. The data access itself isn't modified.
+macro_rules! impl_unary_op {
+    ($($trait:ident::$method:ident),+ $(,)?) => {
+        $(
+            impl<Value> $trait for &Da<Value>
+            where
+                Value: $trait + Copy + 'static,
+            {
+                type Output = Value::Output;
+
+                /// Applies the operator to a copy of the current underlying data.
+                fn $method(self) -> Self::Output {
+                    self.copy_value().$method()
+                }
+            }
+
+            impl<Value> $trait for &Oda<Value>
+            where
+                Value: $trait + Copy + 'static,
+            {
+                type Output = Option<Value::Output>;
+
+                /// Applies the operator to a copy of the current underlying data, or returns `None`
+                /// if there is no data.
+                fn $method(self) -> Self::Output {
+                    self.copy_value().map($trait::$method)
+                }
+            }
+        )+
+    };
+}
+
+impl_unary_op!(Not::not, Neg::neg);
+
+#[cfg(feature = "std")]
+impl<Value> Write for Da<Value>
+where
+    Value: Write + Clone + 'static,
+{
+    /// Writes to the underlying data, see [`Da::write_with()`] for when it's cloned.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_with(|data| data.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_with(|data| data.flush())
+    }
+}
+
+impl<Value> FromStr for Da<Value>
+where
+    Value: FromStr + 'static,
+{
+    type Err = Value::Err;
+
+    /// Idiomatic to calling [`Self::parse(string)`](Self::parse()).
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::parse(string)
+    }
+}
+
+impl<Value> FromStr for Oda<Value>
+where
+    Value: FromStr + 'static,
+{
+    type Err = Value::Err;
+
+    /// Idiomatic to calling [`Self::parse(string)`](Self::parse()).
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::parse(string)
+    }
+}
+
+impl<Value> Clone for Da<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`Da`] pointing to the **exact same** value as the original [`Da`].
+    ///
+    /// Setting the data through either [`Da`] changes it for both. See [`Da::clone_independent()`]
+    /// to create a [`Da`] which doesn't share state.
+    fn clone(&self) -> Self {
+        Self {
+            current_ref: self.current_ref.clone(),
+            callbacks: self.callbacks.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    struct DummyData {
+        text: String,
+        num: u64,
+    }
+
+    impl DummyData {
+        fn new(text: &str, num: u64) -> Self {
+            let text = text.to_owned();
+            Self { text, num }
+        }
+    }
+
+    #[cfg(test)]
+    mod data_access {
+        use std::{collections::HashSet, thread};
+
+        use super::*;
+
+        fn get_default() -> Da<DummyData> {
+            Da::new(DummyData::default())
+        }
+
+        #[test]
+        fn holds_data() {
+            let data_access = Da::new(DummyData::default());
+            assert_eq!(*data_access.get(), DummyData::default());
+        }
+
+        #[test]
+        fn mutate_data() {
+            let data_access = get_default();
+            let mutated_dummy = DummyData::new("I'm different!", 0);
+
+            data_access.set(DummyData::new("I'm different!", 0));
+            assert_eq!(*data_access.get(), mutated_dummy);
+        }
+
+        #[test]
+        fn old_reference() {
+            let data_access = get_default();
+            let mutated_dummy = DummyData::new("I'm different!", 0);
+
+            // Get a reference to the original data
+            let initial_content = data_access.get();
+
+            // Set new data
+            data_access.set(DummyData::new("I'm different!", 0));
+
+            assert_eq!(*initial_content, DummyData::default());
+            assert_eq!(*data_access.get(), mutated_dummy);
+        }
+
+        #[test]
+        fn concurrency() {
+            let data_access = get_default();
+            let data_access_clone = data_access.clone();
+
+            let dummy_data = DummyData::new("I'm different!", 0);
+            let dummy_data_clone = DummyData::new("I'm different!", 0);
+
+            // Get a reference to the original data
+            let initial_content = data_access.get();
+
+            thread::spawn(move || {
+                assert_eq!(*data_access.get(), DummyData::default());
+
+                let mutated_dummy = dummy_data;
+                data_access.set(mutated_dummy.clone());
+
+                assert_eq!(*data_access.get(), mutated_dummy);
+            })
+            .join()
+            .unwrap();
+
+            // Original reference still valid.
+            assert_eq!(*initial_content, DummyData::default());
+            // New references reference new value.
+            assert_eq!(*data_access_clone.get(), dummy_data_clone);
+
+            // Arc will get freed after initial_count is dropped.
+            assert_eq!(Arc::weak_count(&initial_content), 0);
+            assert_eq!(Arc::strong_count(&initial_content), 1);
+        }
+
+        #[test]
+        fn race_condition() {
+            let data_access = get_default();
+            let data_access_clone = data_access.clone();
+
+            let dummy_data = DummyData::new("I'm different!", 0);
+            let dummy_data_clone = dummy_data.clone();
+
+            // Reference to data before the mutation.
+            let before_mutate = data_access_clone.get();
+            // Will be re-assigned to the value set during the mutate closure.
+            let mut set_value = Arc::new(DummyData::default());
+
+            // Starts mutation
+            data_access.mutate(|mut data| {
+                data.num += 1;
+                assert_eq!(*data_access.get(), DummyData::default());
+
+                // Reads & modifies the value during the mutation.
+                {
+                    let during_mutate = data_access_clone.get();
+
+                    assert_eq!(*before_mutate, *during_mutate);
+
+                    data_access_clone.set(dummy_data_clone.clone());
+                    assert_eq!(*data_access_clone.get(), dummy_data_clone);
+
+                    set_value = data_access_clone.get();
+                }
+
+                // The value should be the modified one
+                assert_eq!(*data_access.get(), dummy_data);
+                // Finishes the mutation.
+                data
+            });
+
+            // The value now will be the mutation value, as it was the last process to modify the Da.
+            assert_eq!(*data_access.get(), DummyData::new("", 1));
+            // The pointer to the value set during the mutation will still be valid.
+            assert_eq!(*set_value, dummy_data);
+        }
+
+        #[test]
+        fn copy_value() {
+            let da = Da::new(5);
+            let copied_value = da.copy_value();
+
+            assert_eq!(*da.get(), copied_value);
+            assert_eq!(copied_value, 5);
+
+            da.mutate(|val| val + 1);
+
+            assert_ne!(*da.get(), copied_value);
+        }
+
+        #[test]
+        /// The previous value must be returned.
+        fn returns_old_data() {
+            let da = Da::new(DummyData::default());
+            let set = da.set(DummyData::new("a", 1));
+            assert_eq!(DummyData::default(), *set);
+        }
+
+        /// Poisons the lock on the given [`Da`] by panicking whilst holding it.
+        #[cfg(all(feature = "std", not(feature = "parking_lot")))]
+        fn poison(data_access: &Da<DummyData>) {
+            use crate::data_access::locking_mutate::Lock;
+
+            let clone = data_access.clone();
+            let result = thread::spawn(move || {
+                let _lock = Lock::lock(&clone);
+                panic!("Poisoning the lock");
+            })
+            .join();
+            assert!(result.is_err());
+        }
+
+        #[test]
+        #[cfg(all(feature = "std", not(feature = "parking_lot")))]
+        fn recover_from_poison() {
+            let data_access = get_default();
+            // The lock is healthy.
+            assert!(!data_access.recover_from_poison());
+
+            poison(&data_access);
+            assert!(data_access.recover_from_poison());
+            assert!(!data_access.recover_from_poison());
+
+            assert_eq!(*data_access.get(), DummyData::default());
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(any(feature = "parking_lot", feature = "recover_poison"))
+        ))]
+        #[should_panic(
+            expected = "A thread panicked whilst holding the lock on the underlying data."
+        )]
+        fn poisoned_panics() {
+            let data_access = get_default();
+            poison(&data_access);
+            data_access.get();
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(any(feature = "parking_lot", feature = "recover_poison"))
+        ))]
+        #[should_panic(expected = "Custom message")]
+        fn poisoned_expect_unlocked() {
+            let data_access = get_default();
+            poison(&data_access);
+            data_access.expect_unlocked("Custom message");
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(feature = "parking_lot"),
+            feature = "recover_poison"
+        ))]
+        fn poisoned_recovers() {
+            let data_access = get_default();
+            poison(&data_access);
+
+            assert_eq!(*data_access.get(), DummyData::default());
+            // The poison was cleared when acquiring the lock.
+            assert!(!data_access.recover_from_poison());
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(any(feature = "parking_lot", feature = "recover_poison"))
+        ))]
+        /// The fallible variants must return an error rather than panicking.
+        fn poisoned_try_methods() {
+            let data_access = Da::new(DummyData::new("a", 1));
+            poison(&data_access);
+
+            let poisoned = data_access.try_get().unwrap_err();
+            assert_eq!(poisoned.to_string(), POISONED_MESSAGE);
+            // The data is still accessible through the error.
+            assert_eq!(**poisoned.get_ref(), DummyData::new("a", 1));
+            assert_eq!(**poisoned.into_inner(), DummyData::new("a", 1));
+
+            assert!(data_access.try_set(DummyData::default()).is_err());
+            assert!(data_access.try_mutate(|value| value).is_err());
+        }
+
+        #[test]
+        fn try_methods() {
+            let data_access = Da::new(DummyData::new("a", 1));
+
+            let old = data_access.try_set(DummyData::new("b", 2)).unwrap();
+            assert_eq!(*old, DummyData::new("a", 1));
+
+            data_access
+                .try_mutate(|mut value| {
+                    value.num += 1;
+                    value
+                })
+                .unwrap();
+            assert_eq!(*data_access.try_get().unwrap(), DummyData::new("b", 3));
+        }
+
+        #[test]
+        fn take() {
+            let da = Da::new(DummyData::new("a", 1));
+            let before_take = da.get();
+
+            let taken = da.take();
+            assert_eq!(*taken, DummyData::new("a", 1));
+            // The taken value is the same allocation that was previously held.
+            assert!(Arc::ptr_eq(&taken, &before_take));
+
+            assert_eq!(*da.get(), DummyData::default());
+        }
+
+        #[test]
+        fn parse() {
+            let da: Da<u32> = Da::parse("42").unwrap();
+            assert_eq!(da.copy_value(), 42);
+            assert!(Da::<u32>::parse(String::from("-1")).is_err());
+
+            let da: Da<String> = "hello".parse().unwrap();
+            assert_eq!(*da.get(), "hello");
+        }
+
+        #[test]
+        fn as_ref_with() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let length = da.as_ref_with(|value| value.text.len());
+            assert_eq!(length, 1);
+            assert_eq!(da.borrow_current(|value| value.num), 1);
+        }
+
+        #[test]
+        fn borrow_arc() {
+            let da = Da::new(DummyData::new("a", 1));
+            let reference = da.get();
+
+            assert!(da.borrow_arc(|arc| Arc::ptr_eq(arc, &reference)));
+            // The reference count isn't changed.
+            assert_eq!(da.borrow_arc(Arc::strong_count), 2);
+        }
+
+        #[test]
+        /// Clones share state, whilst independent clones don't.
+        fn clone_independent() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let shared = da.clone();
+            shared.set(DummyData::new("b", 2));
+            assert_eq!(*da.get(), DummyData::new("b", 2));
+
+            let independent = da.clone_independent();
+            assert!(!independent.state_shared(&da));
+            assert!(!independent.ptr_equal(&da));
+            independent.set(DummyData::new("c", 3));
+            assert_eq!(*da.get(), DummyData::new("b", 2));
+            assert_eq!(*independent.get(), DummyData::new("c", 3));
+        }
+
+        #[test]
+        fn strong_counts() {
+            let da = Da::new(DummyData::new("a", 1));
+            assert_eq!(da.value_strong_count(), 1);
+            assert_eq!(da.outer_strong_count(), 1);
+
+            let reference = da.get();
+            let clone = da.clone();
+            assert_eq!(da.value_strong_count(), 2);
+            assert_eq!(clone.outer_strong_count(), 2);
+
+            drop(reference);
+            drop(clone);
+            assert_eq!(da.value_strong_count(), 1);
+            assert_eq!(da.outer_strong_count(), 1);
+        }
+
+        #[test]
+        /// The guard must keep referencing the data from when it was created.
+        fn index_guard() {
+            let da = Da::new(vec![1, 2, 3]);
+            let guard = da.index_guard(1);
+            assert_eq!(*guard, 2);
+            assert_eq!(*da.index_guard(1..), [2, 3]);
+
+            da.set(vec![4, 5, 6]);
+            assert_eq!(*guard, 2);
+            assert_eq!(*Da::new([7, 8]).index_guard(0), 7);
+        }
+
+        #[test]
+        #[should_panic]
+        fn index_guard_out_of_bounds() {
+            Da::new(vec![1]).index_guard(1);
+        }
+
+        #[test]
+        fn from_fn() {
+            let da = Da::from_fn(|| DummyData::new("a", 1));
+            assert_eq!(*da.get(), DummyData::new("a", 1));
+        }
+
+        #[test]
+        /// The operators must apply to a copy, leaving the underlying data unmodified.
+        fn unary_operators() {
+            let flag = Da::new(true);
+            let negated: bool = !&flag;
+            assert!(!negated);
+            assert!(flag.copy_value());
+
+            let num: i32 = -&Da::new(5i32);
+            assert_eq!(num, -5);
+        }
+
+        #[test]
+        /// The weak reference must not keep replaced data alive.
+        fn get_weak() {
+            let da = Da::new(DummyData::new("a", 1));
+            let weak = da.get_weak();
+            assert_eq!(*weak.upgrade().unwrap(), DummyData::new("a", 1));
+            assert_eq!(da.value_strong_count(), 1);
+
+            da.set(DummyData::new("b", 2));
+            assert!(weak.upgrade().is_none());
+        }
+
+        #[test]
+        /// Conversions must be snapshots of the underlying data.
+        fn into_arc() {
+            let da = Da::new(DummyData::new("a", 1));
+            let arc = Arc::<DummyData>::from(da.clone());
+            da.set(DummyData::new("b", 2));
+
+            assert_eq!(*arc, DummyData::new("a", 1));
+            let value: Arc<DummyData> = da.into();
+            assert_eq!(DummyData::clone(&value), DummyData::new("b", 2));
+        }
+
+        #[test]
+        /// The pointer must identify the shared state, with the layout described by the docs.
+        fn as_raw_ptr() {
+            let da = Da::new(DummyData::new("a", 1));
+            assert_eq!(da.as_raw_ptr(), da.clone().as_raw_ptr());
+            assert_ne!(
+                da.as_raw_ptr(),
+                Da::new(DummyData::new("a", 1)).as_raw_ptr()
+            );
+            assert_eq!(
+                core::mem::size_of::<Da<DummyData>>(),
+                3 * core::mem::size_of::<usize>()
+            );
+        }
+
+        #[test]
+        fn as_ref() {
+            let da = Da::new(DummyData::new("a", 1));
+            let clone = da.clone();
+
+            let mutex: &Arc<Mutex<Arc<DummyData>>> = da.as_ref();
+            assert_eq!(Arc::as_ptr(mutex) as *const (), clone.mutex_ptr());
+            assert_eq!(**acquire_lock(mutex), DummyData::new("a", 1));
+        }
+
+        #[test]
+        fn number_formatting() {
+            assert_eq!(format!("{:08x}", Da::new(255u32)), "000000ff");
+            assert_eq!(format!("{:#X}", Da::new(255u32)), "0xFF");
+            assert_eq!(format!("{:b}", Da::new(5u8)), "101");
+            assert_eq!(format!("{:o}", Da::new(8u8)), "10");
+            assert_eq!(format!("{:e}", Da::new(1500f64)), "1.5e3");
+            assert_eq!(format!("{:E}", Da::new(1500f64)), "1.5E3");
+        }
+
+        #[test]
+        fn set_arc() {
+            let da = Da::new(DummyData::new("a", 1));
+            let shared = Arc::new(DummyData::new("b", 2));
+
+            let old = da.set_arc(shared.clone());
+            assert_eq!(*old, DummyData::new("a", 1));
+            // The given allocation is reused.
+            assert!(Arc::ptr_eq(&da.get(), &shared));
+        }
+
+        #[test]
+        fn map_arc() {
+            /// Implements neither `Clone` nor `Copy`.
+            struct Celsius(f64);
+
+            let celsius = Da::new(Celsius(100.0));
+            let fahrenheit = celsius.map_arc(|celsius| Arc::new(celsius.0 * 1.8 + 32.0));
+            assert_eq!(fahrenheit.copy_value(), 212.0);
+
+            // The result is a snapshot.
+            celsius.set(Celsius(0.0));
+            assert_eq!(fahrenheit.copy_value(), 212.0);
+
+            // The same `Arc` may be returned.
+            let same = celsius.map_arc(|celsius| celsius);
+            assert!(Arc::ptr_eq(&same.get(), &celsius.get()));
+        }
+
+        #[test]
+        fn pipe() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let num = da.pipe(|value| value.num + 1);
+            assert_eq!(Da::new(num).pipe_with(|num| num * 2), 4);
+            assert_eq!(da.pipe_with(|value| value.text.clone()), "a");
+        }
+
+        #[test]
+        fn mutate_with_previous() {
+            let da = Da::new(DummyData::new("a", 1));
+            let before = da.get();
+
+            let (old, new) = da.mutate_with_previous(|mut value| {
+                value.num += 1;
+                value
+            });
+
+            assert!(Arc::ptr_eq(&old, &before));
+            assert!(Arc::ptr_eq(&new, &da.get()));
+            assert_eq!(*new, DummyData::new("a", 2));
+        }
+
+        #[test]
+        /// The new value must be stored & the callbacks notified, with the extra result returned.
+        fn mutate_returning() {
+            let da = Da::new(DummyData::new("a", 1));
+            let notified = Da::new(0);
+            let _handle = da.on_set({
+                let notified = notified.clone();
+                move |value| {
+                    notified.set(value.num);
+                }
+            });
+
+            let previous = da.mutate_returning(|mut value| {
+                let previous = value.num;
+                value.num = 5;
+                (value, previous)
+            });
+
+            assert_eq!(previous, 1);
+            assert_eq!(*da.get(), DummyData::new("a", 5));
+            assert_eq!(notified.copy_value(), 5);
+        }
+
+        #[test]
+        /// Existing references must be unaffected by writes.
+        fn write_with() {
+            let da = Da::new(vec![1]);
+            let before = da.get();
+
+            assert_eq!(da.write_with(|vec| vec.pop()), Some(1));
+            // Without outstanding references the data is modified in place.
+            let address = Arc::as_ptr(&da.get());
+            da.write_with(|vec| vec.push(2));
+
+            assert_eq!(*before, vec![1]);
+            assert_eq!(*da.get(), vec![2]);
+            assert_eq!(Arc::as_ptr(&da.get()), address);
+        }
+
+        #[test]
+        fn fanout() {
+            let first = Da::new(DummyData::new("a", 1));
+            let second = Da::new(DummyData::new("b", 2));
+            let before = first.get();
+
+            // Clones share a lock, which must only be acquired once.
+            Da::fanout(&[&second, &first, &first.clone()], DummyData::new("c", 3));
+
+            assert_eq!(*first.get(), DummyData::new("c", 3));
+            assert!(first.ptr_equal(&second));
+            assert_eq!(*before, DummyData::new("a", 1));
+            Da::fanout(&[], DummyData::default());
+        }
+
+        #[test]
+        /// Fanning out in opposite orders from different threads mustn't deadlock.
+        fn fanout_concurrent() {
+            let first = Da::new(0);
+            let second = Da::new(0);
+
+            let handles: Vec<_> = (0..2)
+                .map(|thread| {
+                    let (first, second) = (first.clone(), second.clone());
+                    thread::spawn(move || {
+                        for value in 0..500 {
+                            match thread {
+                                0 => Da::fanout(&[&first, &second], value),
+                                _ => Da::fanout(&[&second, &first], value),
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            assert_eq!(first.copy_value(), second.copy_value());
+        }
+
+        #[test]
+        fn assign_ops() {
+            let mut da = Da::new(10u32);
+            let before = da.get();
+
+            da += 5;
+            da -= 3;
+            da *= 4;
+            da /= 6;
+            da %= 5;
+            assert_eq!(da.copy_value(), 3);
+
+            da |= 0b1100;
+            da &= 0b0110;
+            da ^= 0b0011;
+            da <<= 2;
+            da >>= 1;
+            assert_eq!(da.copy_value(), 0b1010);
+
+            // Existing references are unaffected.
+            assert_eq!(*before, 10);
+        }
+
+        #[test]
+        /// Concurrent assignments must never be lost.
+        fn assign_ops_concurrent() {
+            let da = Da::new(0);
+
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    let mut da = da.clone();
+                    thread::spawn(move || (0..500).for_each(|_| da += 1))
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            assert_eq!(da.copy_value(), 2000);
+        }
+
+        #[test]
+        #[cfg(feature = "parking_lot")]
+        fn timeout() {
+            use crate::data_access::locking_mutate::Lock;
+            use std::time::Duration;
+
+            let da = Da::new(1);
+            assert_eq!(da.get_timeout(Duration::ZERO).as_deref(), Some(&1));
+            assert!(da.set_timeout(2, Duration::ZERO));
+
+            let lock = Lock::lock(&da);
+            assert!(da.get_timeout(Duration::from_millis(10)).is_none());
+            assert!(!da.set_timeout(3, Duration::from_millis(10)));
+            drop(lock);
+
+            assert_eq!(da.copy_value(), 2);
+        }
+
+        #[test]
+        fn try_conversions() {
+            let da: Da<u8> = Da::try_from_value(200u32).unwrap();
+            assert_eq!(da.copy_value(), 200);
+            assert!(Da::<u8>::try_from_value(300u32).is_err());
+
+            let converted: Da<i8> = Da::new(100u8).try_convert().unwrap();
+            assert_eq!(converted.copy_value(), 100);
+            assert!(da.try_convert::<i8>().is_err());
+        }
+
+        #[test]
+        fn identity() {
+            let da = Da::new(DummyData::new("a", 1));
+            let clone = da.clone();
+            let acquired = Da::acquire(da.get());
+            let equal = Da::new(DummyData::new("a", 1));
+
+            assert!(da.ptr_equal(&clone) && da.state_shared(&clone));
+            assert!(da.ptr_equal(&acquired) && !da.state_shared(&acquired));
+            assert!(!da.ptr_equal(&equal) && !da.state_shared(&equal));
+            assert_eq!(da, equal);
+
+            // Setting new data only affects the clone.
+            da.set(DummyData::new("a", 1));
+            assert!(da.ptr_equal(&clone));
+            assert!(!da.ptr_equal(&acquired));
+        }
+
+        #[test]
+        fn pointer() {
+            let da = Da::new(1);
+            let da_clone = da.clone();
+            let before = format!("{da:p}");
+
+            assert_eq!(before, format!("{da_clone:p}"));
+            assert_eq!(before, format!("{:p}", da.get()));
+
+            da.set(2);
+            assert_ne!(before, format!("{da:p}"));
+            assert_eq!(format!("{da:p}"), format!("{da_clone:p}"));
+            // The lock remains the same.
+            assert_eq!(da.mutex_ptr(), da_clone.mutex_ptr());
+            assert_ne!(da.mutex_ptr(), Da::new(2).mutex_ptr());
+        }
+
+        #[test]
+        /// A panic whilst mutating must leave the previous data in place, as the new data is only
+        /// set once the function returns.
+        fn mutate_unwind() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            // `Da` is only unwind safe with the `std` lock, but `mutate()` never modifies the
+            // data in place.
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                da.mutate(|mut value| {
+                    value.num += 1;
+                    panic!("Panicked whilst mutating");
+                })
+            }));
+
+            assert!(result.is_err());
+            assert_eq!(*da.get(), DummyData::new("a", 1));
+        }
+
+        #[test]
+        /// A panic whilst modifying the data in place leaves it partially modified, which is why
+        /// `Da` isn't unwind safe without lock poisoning.
+        fn mutate_in_place_unwind() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                da.mutate_in_place(|value| {
+                    value.num += 1;
+                    panic!("Panicked whilst mutating");
+                })
+            }));
+            assert!(result.is_err());
+
+            // The `std` lock is poisoned, unless the poison is recovered from automatically.
+            #[cfg(all(
+                feature = "std",
+                not(feature = "parking_lot"),
+                not(feature = "recover_poison")
+            ))]
+            {
+                assert!(da.try_get().is_err());
+                assert!(da.recover_from_poison());
+            }
+
+            assert_eq!(*da.get(), DummyData::new("a", 2));
+        }
+
+        #[test]
+        fn mutate_in_place() {
+            let da = Da::new(DummyData::new("a", 1));
+            let before = da.get();
+
+            // An existing reference forces a clone.
+            da.mutate_in_place(|value| value.num += 1);
+            assert_eq!(*before, DummyData::new("a", 1));
+            drop(before);
+
+            let address = Arc::as_ptr(&da.get());
+            da.mutate_in_place(|value| value.num += 1);
+            assert_eq!(*da.get(), DummyData::new("a", 3));
+            assert_eq!(Arc::as_ptr(&da.get()), address);
+
+            da.mutate_efficient(|value| value.num += 1);
+            assert_eq!(*da.get(), DummyData::new("a", 4));
+            assert_eq!(Arc::as_ptr(&da.get()), address);
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn io_write() {
+            use std::io::Write;
+
+            let mut da = Da::new(Vec::new());
+            write!(da, "Hello {}", 5).unwrap();
+            da.flush().unwrap();
+
+            assert_eq!(*da.get(), b"Hello 5");
+        }
+
+        #[test]
+        /// The clone must be independent of the underlying data.
+        fn get_cloned() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let cloned = da.get_cloned();
+            da.mutate(|mut value| {
+                value.num += 1;
+                value
+            });
+
+            assert_eq!(cloned, DummyData::new("a", 1));
+            assert_eq!(*da.get(), DummyData::new("a", 2));
+        }
+
+        #[test]
+        /// The function must be called once & the same instance must be returned for chaining.
+        fn inspect() {
+            let da = Da::new(DummyData::new("a", 1));
+            let mut calls = 0;
+
+            let returned = da.inspect(|value| {
+                calls += 1;
+                assert_eq!(*value, DummyData::new("a", 1));
+            });
+            assert!(std::ptr::eq(returned, &da));
+            assert_eq!(calls, 1);
+
+            // The lock is released before chained calls.
+            da.inspect(|value| assert_eq!(value.num, 1))
+                .set(DummyData::new("b", 2));
+            assert_eq!(*da.get(), DummyData::new("b", 2));
+        }
+
+        #[test]
+        /// The data must only be replaced if the new value differs.
+        fn conditional_set() {
+            let da = Da::new(DummyData::new("a", 1));
+            let before = da.get();
+
+            assert!(!da.conditional_set(DummyData::new("a", 1)));
+            // The existing allocation is kept.
+            assert!(Arc::ptr_eq(&before, &da.get()));
+
+            assert!(da.conditional_set(DummyData::new("b", 2)));
+            assert_eq!(*da.get(), DummyData::new("b", 2));
+            assert_eq!(*before, DummyData::new("a", 1));
+        }
+
+        #[test]
+        /// The guard must give access to the value & block writers until it's dropped.
+        fn lock_guard() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let guard = da.lock_guard();
+            assert_eq!(guard.text, "a");
+            assert_eq!(*guard, DummyData::new("a", 1));
+
+            let clone = da.clone();
+            let writer = thread::spawn(move || clone.set(DummyData::new("b", 2)));
+            thread::sleep(std::time::Duration::from_millis(50));
+            // The writer is waiting on the lock.
+            assert!(!writer.is_finished());
+            assert_eq!(guard.num, 1);
+
+            drop(guard);
+            writer.join().unwrap();
+            assert_eq!(*da.lock_guard(), DummyData::new("b", 2));
+        }
+
+        #[test]
+        fn collection_helpers() {
+            let da = Da::new(vec![1, 2]);
+            let before = da.get();
+
+            da.push_into(3);
+            assert_eq!(*da.get(), vec![1, 2, 3]);
+            // Existing references are unaffected.
+            assert_eq!(*before, vec![1, 2]);
+
+            assert_eq!(da.pop_from(), Some(3));
+            assert_eq!(da.pop_from(), Some(2));
+            assert_eq!(da.pop_from(), Some(1));
+            assert_eq!(da.pop_from(), None);
+
+            let da = Da::new(String::from("Hello"));
+            da.push_str(", World");
+            assert_eq!(*da.get(), "Hello, World");
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn insert_entry() {
+            let da = Da::new(std::collections::HashMap::new());
+            assert_eq!(da.insert_entry("a", 1), None);
+            assert_eq!(da.insert_entry("a", 2), Some(1));
+            assert_eq!(da.get()["a"], 2);
+        }
+
+        #[test]
+        /// The values must be exchanged, with references to the old values remaining valid.
+        fn swap_with() {
+            let first = Da::new(DummyData::new("a", 1));
+            let second = Da::new(DummyData::new("b", 2));
+            let (first_before, second_before) = (first.get(), second.get());
+
+            first.swap_with(&second);
+            assert!(Arc::ptr_eq(&first.get(), &second_before));
+            assert!(Arc::ptr_eq(&second.get(), &first_before));
+
+            // Swapping in the opposite direction restores the original values.
+            second.swap_with(&first);
+            assert_eq!(*first.get(), DummyData::new("a", 1));
+            assert_eq!(*second.get(), DummyData::new("b", 2));
+
+            assert_eq!(*first_before, DummyData::new("a", 1));
+            assert_eq!(*second_before, DummyData::new("b", 2));
+        }
+
+        #[test]
+        /// The old value must be returned, with the operation applied to the underlying data.
+        fn fetch_operations() {
+            let da = Da::new(5u32);
+
+            assert_eq!(da.fetch_add(3), 5);
+            assert_eq!(da.fetch_sub(2), 8);
+            assert_eq!(da.fetch_mul(4), 6);
+            assert_eq!(da.fetch_and(0b1010), 24);
+            assert_eq!(da.fetch_or(0b0101), 0b1000);
+            assert_eq!(da.fetch_xor(0b1111), 0b1101);
+            assert_eq!(da.copy_value(), 0b0010);
+        }
+
+        #[test]
+        #[cfg(all(
+            debug_assertions,
+            feature = "std",
+            not(any(feature = "parking_lot", feature = "recover_poison"))
+        ))]
+        /// Unlike the atomic operations, an overflow panics whilst holding the lock, poisoning it.
+        fn fetch_overflow_poisons() {
+            let da = Da::new(u8::MAX);
+
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| da.fetch_add(1)));
+            assert!(result.is_err());
+            assert!(da.try_get().is_err());
+        }
+
+        #[test]
+        fn fetch_max_min() {
+            let da = Da::new(5u32);
+
+            assert_eq!(da.fetch_max(3), 5);
+            assert_eq!(da.fetch_max(9), 5);
+            assert_eq!(da.fetch_min(7), 9);
+            assert_eq!(da.fetch_min(8), 7);
+            assert_eq!(da.copy_value(), 7);
+        }
+
+        #[test]
+        /// The final value must be the maximum of every value given by any thread.
+        fn fetch_max_concurrent() {
+            let da = Da::new(0usize);
+
+            let handles: Vec<_> = (0..8)
+                .map(|thread| {
+                    let da = da.clone();
+                    thread::spawn(move || {
+                        for num in 0..1000 {
+                            da.fetch_max((num * 7919 + thread * 104_729) % 100_000);
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            let expected = (0..8)
+                .flat_map(|thread| {
+                    (0..1000).map(move |num| (num * 7919 + thread * 104_729) % 100_000)
+                })
+                .max()
+                .unwrap();
+            assert_eq!(da.copy_value(), expected);
+        }
+
+        #[test]
+        fn copy_and_set() {
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            enum State {
+                Idle,
+                Running,
+            }
+
+            let state = Da::new(State::Idle);
+            assert_eq!(state.copy_and_set(State::Running), State::Idle);
+            assert_eq!(state.copy_and_set(State::Idle), State::Running);
+            assert_eq!(state.copy_value(), State::Idle);
+        }
+
+        #[test]
+        /// Concurrent increments must never be lost.
+        fn fetch_add_concurrent() {
+            let da = Da::new(0usize);
+
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    let da = da.clone();
+                    thread::spawn(move || {
+                        for _ in 0..1000 {
+                            da.fetch_add(1);
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            assert_eq!(da.copy_value(), 8000);
+        }
+
+        #[test]
+        fn zip_with() {
+            let first = Da::new(2u32);
+            let second = Da::new(3u64);
+
+            let sum = first.zip_with(&second, |first, second| *first as u64 + second);
+            assert_eq!(sum.copy_value(), 5);
+
+            // The result is a snapshot, so isn't affected by later mutations.
+            first.set(10);
+            assert_eq!(sum.copy_value(), 5);
+
+            // Zipping with a clone mustn't deadlock.
+            let doubled = first.zip_with(&first.clone(), |first, second| first + second);
+            assert_eq!(doubled.copy_value(), 20);
+        }
+
+        #[test]
+        /// Equal values must hash equally, allowing for membership checks.
+        // The values aren't mutated whilst in the set.
+        #[allow(clippy::mutable_key_type)]
+        fn hash_set_membership() {
+            let set: HashSet<Da<u32>> = [1, 2, 3].into_iter().map(Da::new).collect();
+
+            assert!(set.contains(&Da::new(2)));
+            assert!(!set.contains(&Da::new(4)));
+            assert_eq!(set.len(), 3);
+
+            // Duplicate values are only inserted once.
+            let mut set = set;
+            assert!(!set.insert(Da::new(1)));
+        }
+
+        #[test]
+        /// The merged value must be stored, with the other `Da` left unchanged.
+        fn merge_with() {
+            let config = Da::new(DummyData::new("default", 1));
+            let overrides = Da::new(DummyData::new("custom", 0));
+
+            config.merge_with(&overrides, |config, overrides| DummyData {
+                text: overrides.text,
+                ..config
+            });
+
+            assert_eq!(*config.get(), DummyData::new("custom", 1));
+            assert_eq!(*overrides.get(), DummyData::new("custom", 0));
+
+            // Merging with a clone mustn't deadlock.
+            config.merge_with(&config.clone(), |first, second| {
+                DummyData::new(&first.text, first.num + second.num)
+            });
+            assert_eq!(*config.get(), DummyData::new("custom", 2));
+        }
+
+        #[test]
+        /// Swapping with a clone must not deadlock & has no effect.
+        fn swap_with_self() {
+            let da = Da::new(DummyData::new("a", 1));
+            da.swap_with(&da.clone());
+            assert_eq!(*da.get(), DummyData::new("a", 1));
+        }
+
+        #[test]
+        /// Swapping the same pair in opposing orders from different threads mustn't deadlock.
+        fn swap_with_concurrent() {
+            let first = Da::new(DummyData::new("a", 1));
+            let second = Da::new(DummyData::new("b", 2));
+
+            let handles: Vec<_> = (0..4)
+                .map(|thread| {
+                    let (first, second) = (first.clone(), second.clone());
+                    thread::spawn(move || {
+                        for _ in 0..1000 {
+                            match thread % 2 {
+                                0 => first.swap_with(&second),
+                                _ => second.swap_with(&first),
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            // An even amount of swaps occurred.
+            assert_eq!(*first.get(), DummyData::new("a", 1));
+            assert_eq!(*second.get(), DummyData::new("b", 2));
+        }
+
+        #[test]
+        fn try_into_unique() {
+            let da = Da::new(DummyData::new("a", 1));
+            let callback_calls = Arc::new(Mutex::new(0));
+            let calls = callback_calls.clone();
+            let _handle = da.on_set(move |_| *acquire_lock(&calls) += 1);
+
+            let mut unique = da.try_into_unique().unwrap();
+            unique.num += 1;
+            let da = unique.into_da();
+            assert_eq!(*da.get(), DummyData::new("a", 2));
+
+            // Callbacks are kept.
+            da.set(DummyData::new("b", 3));
+            assert_eq!(*acquire_lock(&callback_calls), 1);
+        }
+
+        #[test]
+        /// Taking ownership must fail whilst other references exist.
+        fn try_into_unique_shared() {
+            let da = Da::new(DummyData::new("a", 1));
+
+            let clone = da.clone();
+            let da = da.try_into_unique().unwrap_err();
+            drop(clone);
+
+            let reference = da.get();
+            let da = da.try_into_unique().unwrap_err();
+            assert_eq!(*da.get(), DummyData::new("a", 1));
+            drop(reference);
+
+            assert_eq!(
+                da.try_into_unique().unwrap().into_inner(),
+                DummyData::new("a", 1)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod optional_data_access {
+        use std::{collections::HashSet, thread};
+
+        use crate::test_data::Data;
+
+        use super::*;
+
+        fn get_default() -> Oda<DummyData> {
+            Oda::new(DummyData::default())
+        }
+
+        #[test]
+        fn default_to_none() {
+            let oda: Oda<DummyData> = Oda::default();
+            assert!(oda.get().is_none());
+        }
+
+        #[test]
+        fn holds_data() {
+            let data_access = Oda::new(DummyData::default());
+            assert_eq!(*data_access.get().unwrap(), DummyData::default());
+        }
+
+        #[test]
+        fn mutate_data() {
+            let data_access = get_default();
+            let mutated_dummy = DummyData::new("I'm different!", 0);
+
+            data_access.set(DummyData::new("I'm different!", 0));
+            assert_eq!(*data_access.get().unwrap(), mutated_dummy);
+        }
+
+        #[test]
+        fn old_reference() {
+            let data_access = get_default();
+            let mutated_dummy = DummyData::new("I'm different!", 0);
+
+            // Get a reference to the original data
+            let initial_content = data_access.get().unwrap();
+
+            // Set new data
+            data_access.set(DummyData::new("I'm different!", 0));
+
+            assert_eq!(*initial_content, DummyData::default());
+            assert_eq!(*data_access.get().unwrap(), mutated_dummy);
+        }
+
+        #[test]
+        fn concurrency() {
+            let data_access = get_default();
+            let data_access_clone = data_access.clone();
+
+            let dummy_data = DummyData::new("I'm different!", 0);
+            let dummy_data_clone = DummyData::new("I'm different!", 0);
+
+            // Get a reference to the original data
+            let initial_content = data_access.get().unwrap();
+
+            thread::spawn(move || {
+                assert_eq!(*data_access.get().unwrap(), DummyData::default());
+
+                let mutated_dummy = dummy_data;
+                data_access.set(mutated_dummy.clone());
+
+                assert_eq!(*data_access.get().unwrap(), mutated_dummy);
+            })
+            .join()
+            .unwrap();
+
+            // Original reference still valid.
+            assert_eq!(*initial_content, DummyData::default());
+            // New references reference new value.
+            assert_eq!(*data_access_clone.get().unwrap(), dummy_data_clone);
+
+            // Arc will get freed after initial_count is dropped.
+            assert_eq!(Arc::weak_count(&initial_content), 0);
+            assert_eq!(Arc::strong_count(&initial_content), 1);
+        }
+
+        #[test]
+        fn race_condition() {
+            let data_access = get_default();
+            let data_access_clone = data_access.clone();
+
+            let dummy_data = DummyData::new("I'm different!", 0);
+            let dummy_data_clone = dummy_data.clone();
+
+            // Reference to data before the mutation.
+            let before_mutate = data_access_clone.get().unwrap();
+            // Will be re-assigned to the value set during the mutate closure.
+            let mut set_value = Arc::new(DummyData::default());
+
+            // Starts mutation
+            data_access.mutate(|mut data| {
+                data.num += 1;
+                assert_eq!(*data_access.get().unwrap(), DummyData::default());
+
+                // Reads & modifies the value during the mutation.
+                {
+                    let during_mutate = data_access_clone.get().unwrap();
+
+                    assert_eq!(*before_mutate, *during_mutate);
+
+                    data_access_clone.set(dummy_data_clone.clone());
+                    assert_eq!(*data_access_clone.get().unwrap(), dummy_data_clone);
+
+                    set_value = data_access_clone.get().unwrap();
+                }
+
+                // The value should be the modified one
+                assert_eq!(*data_access.get().unwrap(), dummy_data);
+                // Finishes the mutation.
+                data
+            });
+
+            // The value now will be the mutation value, as it was the last process to modify the Da.
+            assert_eq!(*data_access.get().unwrap(), DummyData::new("", 1));
+            // The pointer to the value set during the mutation will still be valid.
+            assert_eq!(*set_value, dummy_data);
+        }
+
+        #[test]
+        fn acquire() {
+            let original = Oda::new(DummyData::new("A!", 0));
+            let acquired = Oda::acquire(original.get().unwrap());
+
+            assert_eq!(original.get(), acquired.get());
+
+            // original has a new internal arc.
+            original.set(DummyData::default());
+
+            assert_eq!((*acquired.get().unwrap()), DummyData::new("A!", 0));
+            assert_eq!(*original.get().unwrap(), DummyData::default());
+        }
+
+        #[test]
+        fn set_arc() {
+            let original = Oda::new(DummyData::new("A!", 0));
+
+            let to_replace = Oda::default();
+            to_replace.set_arc(Some(original.get().unwrap()));
+
+            assert_eq!(original.get(), to_replace.get());
+
+            // original has a new internal arc.
+            original.set(DummyData::default());
+
+            assert_eq!((*to_replace.get().unwrap()), DummyData::new("A!", 0));
+            assert_eq!(*original.get().unwrap(), DummyData::default());
+        }
+
+        /// Poisons the lock on the given [`Oda`] by panicking whilst holding it.
+        #[cfg(all(feature = "std", not(feature = "parking_lot")))]
+        fn poison(data_access: &Oda<DummyData>) {
+            use crate::data_access::locking_mutate::Lock;
+
+            let clone = data_access.clone();
+            let result = thread::spawn(move || {
+                let _lock = Lock::lock(&clone);
+                panic!("Poisoning the lock");
+            })
+            .join();
+            assert!(result.is_err());
+        }
+
+        #[test]
+        #[cfg(all(feature = "std", not(feature = "parking_lot")))]
+        fn recover_from_poison() {
+            let data_access = get_default();
+            // The lock is healthy.
+            assert!(!data_access.recover_from_poison());
+
+            poison(&data_access);
+            assert!(data_access.recover_from_poison());
+            assert!(!data_access.recover_from_poison());
+
+            assert_eq!(*data_access.get().unwrap(), DummyData::default());
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(any(feature = "parking_lot", feature = "recover_poison"))
+        ))]
+        #[should_panic(
+            expected = "A thread panicked whilst holding the lock on the underlying data."
+        )]
+        fn poisoned_panics() {
+            let data_access = get_default();
+            poison(&data_access);
+            data_access.get();
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(feature = "parking_lot"),
+            feature = "recover_poison"
+        ))]
+        fn poisoned_recovers() {
+            let data_access = get_default();
+            poison(&data_access);
+
+            assert_eq!(*data_access.get().unwrap(), DummyData::default());
+            // The poison was cleared when acquiring the lock.
+            assert!(!data_access.recover_from_poison());
+        }
+
+        #[test]
+        #[cfg(all(
+            feature = "std",
+            not(any(feature = "parking_lot", feature = "recover_poison"))
+        ))]
+        /// The fallible variants must return an error rather than panicking.
+        fn poisoned_try_methods() {
+            let data_access = get_default();
+            poison(&data_access);
+
+            assert!(data_access.try_get().is_err());
+            assert!(data_access.try_set(DummyData::default()).is_err());
+            assert!(data_access.try_mutate(|value| value).is_err());
+        }
+
+        #[test]
+        fn try_methods() {
+            let data_access = Oda::new(Data::new(1));
+
+            assert_eq!(
+                *data_access.try_set(Data::new(2)).unwrap().unwrap(),
+                1.into()
+            );
+            data_access.try_mutate(|value| value + 1).unwrap();
+            assert_eq!(*data_access.try_get().unwrap().unwrap(), 3.into());
+        }
+
+        #[test]
+        fn take() {
+            let oda = Oda::new(Data::default());
+            assert_eq!(*oda.get().unwrap(), Data::default());
+
+            let taken = oda.take();
+            assert_eq!(*taken.unwrap(), Data::default());
+
+            assert!(oda.get().is_none());
+            assert!(oda.take().is_none());
+        }
+
+        #[test]
+        /// Parsing errors must be returned, rather than an empty `Oda`.
+        fn parse() {
+            let oda: Oda<i32> = Oda::parse("-7").unwrap();
+            assert_eq!(oda.copy_value(), Some(-7));
+            assert!(Oda::<i32>::parse("seven").is_err());
+            assert!("seven".parse::<Oda<i32>>().is_err());
+        }
+
+        #[test]
+        fn as_ref_with() {
+            let oda = Oda::new(Data::new(1));
+            assert!(oda.as_ref_with(|value| value.is_some()));
+
+            oda.take();
+            assert!(oda.borrow_current(|value| value.is_none()));
+        }
+
+        #[test]
+        fn borrow_arc_opt() {
+            let oda = Oda::new(Data::new(1));
+            let reference = oda.get().unwrap();
+
+            assert!(oda.borrow_arc_opt(|arc| Arc::ptr_eq(arc.unwrap(), &reference)));
+            assert_eq!(
+                oda.borrow_arc_opt(|arc| arc.map(Arc::strong_count)),
+                Some(2)
+            );
+
+            oda.take();
+            assert!(oda.borrow_arc_opt(|arc| arc.is_none()));
+        }
+
+        #[test]
+        fn clone_independent() {
+            let oda = Oda::new(Data::new(1));
+
+            let independent = oda.clone_independent();
+            independent.take();
+            assert_eq!(*oda.get().unwrap(), 1.into());
+
+            oda.clone().take();
+            assert!(oda.get().is_none());
+            assert!(oda.clone_independent().get().is_none());
+        }
+
+        #[test]
+        fn strong_counts() {
+            let oda = Oda::new(Data::new(1));
+            let _reference = oda.get();
+            let _clone = oda.clone();
+            assert_eq!(oda.value_strong_count(), 2);
+            assert_eq!(oda.outer_strong_count(), 2);
+
+            oda.take();
+            assert_eq!(oda.value_strong_count(), 0);
+        }
+
+        #[test]
+        fn from_fn() {
+            assert_eq!(
+                *Oda::from_fn(|| Some(Data::new(1))).get().unwrap(),
+                Data::new(1)
+            );
+            assert!(Oda::<Data>::from_fn(|| None).get().is_none());
+        }
+
+        #[test]
+        fn unary_operators() {
+            let flag = Oda::new(true);
+            assert_eq!(!&flag, Some(false));
+            assert_eq!(-&Oda::new(5i32), Some(-5));
+
+            flag.take();
+            assert_eq!(!&flag, None);
+        }
+
+        #[test]
+        fn get_weak() {
+            let oda = Oda::new(Data::new(1));
+            let weak = oda.get_weak().unwrap();
+            assert_eq!(*weak.upgrade().unwrap(), Data::new(1));
+
+            oda.take();
+            assert!(weak.upgrade().is_none());
+            assert!(oda.get_weak().is_none());
+        }
+
+        #[test]
+        /// Conversions must be snapshots of the underlying data.
+        fn into_option() {
+            let oda = Oda::new(Data::new(1));
+            let arc: Option<Arc<Data>> = oda.clone().into();
+            oda.set(Data::new(2));
+
+            assert_eq!(*arc.unwrap(), Data::new(1));
+            assert_eq!(Option::<Data>::from(oda.clone()), Some(Data::new(2)));
+            oda.take();
+            assert_eq!(Option::<Data>::from(oda), None);
+        }
+
+        #[test]
+        fn as_ref() {
+            let oda = Oda::new(Data::new(1));
+
+            let mutex: &Arc<Mutex<Option<Arc<Data>>>> = oda.as_ref();
+            *acquire_lock(mutex) = None;
+            assert!(oda.get().is_none());
+        }
+
+        #[test]
+        fn number_formatting() {
+            let oda = Oda::new(255u32);
+            assert_eq!(format!("{:08x}", oda), "000000ff");
+            assert_eq!(format!("{:b}", oda), "11111111");
+
+            oda.take();
+            assert_eq!(format!("{:x}", oda), "(none)");
+            assert_eq!(format!("{:>8e}", Oda::<f32>::default()), "  (none)");
+        }
+
+        #[test]
+        fn pipe_some() {
+            let oda = Oda::new(Data::new(1));
+            assert_eq!(oda.pipe_some(|value| (*value).clone() + 1), Some(2.into()));
+
+            oda.take();
+            assert!(oda
+                .pipe_some(|_| panic!("The function mustn't be called"))
+                .is_none());
+        }
+
+        #[test]
+        fn mutate_with_previous() {
+            let oda = Oda::new(Data::new(1));
+
+            let (old, new) = oda.mutate_with_previous(|value| value + 1);
+            assert_eq!(*old.unwrap(), 1.into());
+            assert!(Arc::ptr_eq(new.as_ref().unwrap(), &oda.get().unwrap()));
+            assert_eq!(*new.unwrap(), 2.into());
+
+            // The function isn't called when there's no data.
+            oda.take();
+            let (old, new) = oda.mutate_with_previous(|_| panic!("Mustn't be called"));
+            assert!(old.is_none() && new.is_none());
+        }
+
+        #[test]
+        fn mutate_returning() {
+            let oda = Oda::new(Data::new(1));
+
+            let had_data = oda.mutate_returning(|value| (None, value.is_some()));
+            assert!(had_data);
+            assert!(oda.get().is_none());
+
+            let had_data = oda.mutate_returning(|value| (Some(Data::new(2)), value.is_some()));
+            assert!(!had_data);
+            assert_eq!(*oda.get().unwrap(), Data::new(2));
+        }
+
+        #[test]
+        fn get_cloned() {
+            let oda = Oda::new(Data::new(1));
+
+            let cloned = oda.get_cloned();
+            oda.mutate(|value| value + 1);
+            assert_eq!(cloned.unwrap(), 1.into());
+            assert_eq!(*oda.get().unwrap(), 2.into());
+
+            oda.take();
+            assert!(oda.get_cloned().is_none());
+        }
+
+        #[test]
+        fn inspect() {
+            let oda = Oda::new(Data::new(1));
+            let mut calls = 0;
+
+            let returned = oda.inspect(|value| {
+                calls += 1;
+                assert_eq!(*value.unwrap(), 1.into());
+            });
+            assert!(std::ptr::eq(returned, &oda));
+            assert_eq!(calls, 1);
+
+            oda.take();
+            oda.inspect(|value| assert!(value.is_none()))
+                .set(Data::new(2));
+            assert_eq!(*oda.get().unwrap(), 2.into());
+        }
+
+        #[test]
+        /// Every combination of existing & new data must be handled.
+        fn conditional_set() {
+            let oda: Oda<Data> = Oda::default();
+
+            // (None, None)
+            assert!(!oda.conditional_set(None));
+            // (None, Some)
+            assert!(oda.conditional_set(Some(Data::new(1))));
+            assert_eq!(*oda.get().unwrap(), 1.into());
+
+            // (Some, Some) with an equal value
+            let before = oda.get().unwrap();
+            assert!(!oda.conditional_set(Some(Data::new(1))));
+            assert!(Arc::ptr_eq(&before, &oda.get().unwrap()));
+            // (Some, Some) with a different value
+            assert!(oda.conditional_set(Some(Data::new(2))));
+            assert_eq!(*oda.get().unwrap(), 2.into());
+
+            // (Some, None)
+            assert!(oda.conditional_set(None));
+            assert!(oda.get().is_none());
+        }
+
+        #[test]
+        /// No data must hash differently to any value.
+        #[allow(clippy::mutable_key_type)]
+        fn hash_set_membership() {
+            let set: HashSet<Oda<u32>> = [Oda::new(1), Oda::default()].into_iter().collect();
+
+            assert!(set.contains(&Oda::new(1)));
+            assert!(set.contains(&Oda::default()));
+            assert!(!set.contains(&Oda::new(0)));
+        }
+
+        #[test]
+        fn transpose_option() {
+            assert!(Oda::<Option<u32>>::default().transpose().is_none());
+
+            let transposed = Oda::new(None::<u32>).transpose().unwrap();
+            assert!(transposed.get().is_none());
+
+            let transposed = Oda::new(Some(1u32)).transpose().unwrap();
+            assert_eq!(transposed.copy_value(), Some(1));
+        }
+
+        #[test]
+        fn transpose_result() {
+            let transposed = Oda::<Result<u32, String>>::default().transpose().unwrap();
+            assert!(transposed.get().is_none());
+
+            let transposed = Oda::new(Ok::<u32, String>(1)).transpose().unwrap();
+            assert_eq!(transposed.copy_value(), Some(1));
+
+            let transposed = Oda::new(Err::<u32, _>(String::from("error"))).transpose();
+            assert_eq!(transposed.unwrap_err(), "error");
+        }
+
+        #[test]
+        fn merge_with() {
+            let first = Oda::new(Data::new(1));
+            let second: Oda<Data> = Oda::default();
+
+            let merge = |first: Option<Data>, second: Option<Data>| second.or(first);
+
+            first.merge_with(&second, merge);
+            assert_eq!(*first.get().unwrap(), 1.into());
+            assert!(second.get().is_none());
+
+            second.set(Data::new(2));
+            first.merge_with(&second, merge);
+            assert_eq!(*first.get().unwrap(), 2.into());
+            assert_eq!(*second.get().unwrap(), 2.into());
+        }
+
+        #[test]
+        fn zip_with() {
+            let first = Oda::new(2u32);
+            let second: Oda<u32> = Oda::default();
+
+            let sum = |first: Option<&u32>, second: Option<&u32>| {
+                first.copied().unwrap_or_default() + second.copied().unwrap_or_default()
+            };
+
+            assert_eq!(first.zip_with(&second, sum).copy_value(), 2);
+            second.set(3);
+            assert_eq!(first.zip_with(&second, sum).copy_value(), 5);
+            assert_eq!(first.zip_with(&first.clone(), sum).copy_value(), 4);
+        }
+
+        #[test]
+        fn zip() {
+            let first = Oda::new(Data::new(1));
+            let second: Oda<u8> = Oda::default();
+
+            assert!(first.zip(&second).get().is_none());
+            second.set(2);
+            assert_eq!(*first.zip(&second).get().unwrap(), (Data::new(1), 2));
+            assert_eq!(
+                *first.zip(&first.clone()).get().unwrap(),
+                (Data::new(1), Data::new(1))
+            );
+        }
+
+        #[test]
+        fn zip_some_with() {
+            let first = Oda::new(2u32);
+            let second = Oda::new(3u32);
+
+            let sum = first.zip_some_with(&second, |first, second| first + second);
+            assert_eq!(sum.copy_value(), Some(5));
+            // The result is a snapshot.
+            first.set(4);
+            assert_eq!(sum.copy_value(), Some(5));
+            assert_eq!(
+                first
+                    .zip_some_with(&first.clone(), |first, second| first * second)
+                    .copy_value(),
+                Some(16)
+            );
+
+            first.take();
+            let sum = first.zip_some_with(&second, |_, _| -> u32 { panic!("Mustn't be called") });
+            assert!(sum.get().is_none());
+
+            // The locks aren't held whilst the function is executing.
+            second.take();
+            first.set(1);
+            second.set(2);
+            let sum = first.zip_some_with(&second, |value, other| {
+                first.set(value + other);
+                second.take();
+                value + other
+            });
+            assert_eq!(sum.copy_value(), Some(3));
+            assert_eq!(first.copy_value(), Some(3));
+            assert!(second.get().is_none());
+        }
+
+        #[test]
+        fn lock_guard() {
+            let oda = Oda::new(Data::new(1));
+            assert_eq!(**oda.lock_guard().as_ref().unwrap(), 1.into());
+
+            oda.take();
+            assert!(oda.lock_guard().is_none());
+        }
+
+        #[test]
+        #[cfg(feature = "parking_lot")]
+        fn timeout() {
+            use crate::data_access::locking_mutate::Lock;
+            use std::time::Duration;
+
+            let oda = Oda::default();
+            assert_eq!(oda.get_timeout(Duration::ZERO), Some(None));
+            assert!(oda.set_timeout(1, Duration::ZERO));
+
+            let lock = Lock::lock(&oda);
+            assert!(oda.get_timeout(Duration::from_millis(10)).is_none());
+            assert!(!oda.set_timeout(2, Duration::from_millis(10)));
+            drop(lock);
+
+            assert_eq!(oda.copy_value(), Some(1));
+        }
+
+        #[test]
+        fn try_conversions() {
+            let oda: Oda<u8> = Oda::try_from_value(200u32).unwrap();
+            assert_eq!(oda.copy_value(), Some(200));
+            assert!(Oda::<u8>::try_from_value(300u32).is_err());
+            assert!(oda.try_convert::<i8>().is_err());
+
+            oda.set(100);
+            assert_eq!(oda.try_convert::<i8>().unwrap().copy_value(), Some(100));
+            oda.take();
+            assert!(oda.try_convert::<i8>().unwrap().get().is_none());
+        }
+
+        #[test]
+        fn fanout() {
+            let first = Oda::new(DummyData::new("a", 1));
+            let second = Oda::default();
+
+            Oda::fanout(&[&first, &second], DummyData::new("b", 2));
+
+            assert_eq!(*second.get().unwrap(), DummyData::new("b", 2));
+            assert!(first.ptr_equal(&second));
+        }
+
+        #[test]
+        fn identity() {
+            let oda = Oda::new(DummyData::new("a", 1));
+            let clone = oda.clone();
+            let acquired = Oda::acquire(oda.get().unwrap());
+
+            assert!(oda.ptr_equal(&clone) && oda.state_shared(&clone));
+            assert!(oda.ptr_equal(&acquired) && !oda.state_shared(&acquired));
+
+            // No data has no allocation to compare.
+            oda.take();
+            assert!(!oda.ptr_equal(&clone) && oda.state_shared(&clone));
+        }
+
+        #[test]
+        fn expect() {
+            let oda = Oda::new(DummyData::new("a", 1));
+            assert_eq!(*oda.expect("Set above"), DummyData::new("a", 1));
+            assert_eq!(*oda.unwrap(), DummyData::new("a", 1));
+        }
+
+        #[test]
+        #[should_panic(expected = "Custom message")]
+        fn expect_empty() {
+            Oda::<DummyData>::default().expect("Custom message");
+        }
+
+        #[test]
+        #[should_panic(expected = "Called `Oda::unwrap()` on an `Oda` with no data")]
+        fn unwrap_empty() {
+            Oda::<DummyData>::default().unwrap();
+        }
 
-            let dummy_data = DummyData::new("I'm different!", 0);
-            let dummy_data_clone = DummyData::new("I'm different!", 0);
+        #[test]
+        fn pointer() {
+            let oda = Oda::new(1);
+            assert_eq!(format!("{oda:p}"), format!("{:p}", oda.get().unwrap()));
+            assert_eq!(format!("{:p}", oda.clone()), format!("{oda:p}"));
 
-            // Get a reference to the original data
-            let initial_content = data_access.get();
+            oda.take();
+            assert_eq!(format!("{oda:p}"), format!("{:p}", std::ptr::null::<i32>()));
+        }
 
-            thread::spawn(move || {
-                assert_eq!(*data_access.get(), DummyData::default());
+        #[test]
+        fn copy_and_set() {
+            let oda = Oda::default();
 
-                let mutated_dummy = dummy_data;
-                data_access.set(mutated_dummy.clone());
+            assert_eq!(oda.copy_and_set(Some(1)), None);
+            assert_eq!(oda.copy_and_set(Some(2)), Some(1));
+            assert_eq!(oda.copy_and_set(None), Some(2));
+            assert_eq!(oda.copy_value(), None);
+        }
 
-                assert_eq!(*data_access.get(), mutated_dummy);
-            })
-            .join()
-            .unwrap();
+        #[test]
+        /// The old value must be returned, or `None` if there is no data.
+        fn fetch_operations() {
+            let oda = Oda::new(5i32);
 
-            // Original reference still valid.
-            assert_eq!(*initial_content, DummyData::default());
-            // New references reference new value.
-            assert_eq!(*data_access_clone.get(), dummy_data_clone);
+            assert_eq!(oda.fetch_add(3), Some(5));
+            assert_eq!(oda.fetch_sub(10), Some(8));
+            assert_eq!(oda.fetch_mul(-2), Some(-2));
+            assert_eq!(oda.fetch_and(0b110), Some(4));
+            assert_eq!(oda.fetch_or(0b001), Some(4));
+            assert_eq!(oda.fetch_xor(0b111), Some(5));
+            assert_eq!(oda.copy_value(), Some(2));
 
-            // Arc will get freed after initial_count is dropped.
-            assert_eq!(Arc::weak_count(&initial_content), 0);
-            assert_eq!(Arc::strong_count(&initial_content), 1);
+            oda.take();
+            assert_eq!(oda.fetch_add(1), None);
+            assert!(oda.get().is_none());
         }
 
         #[test]
-        fn race_condition() {
-            let data_access = get_default();
-            let data_access_clone = data_access.clone();
+        fn fetch_max_min() {
+            let oda = Oda::new(5i32);
 
-            let dummy_data = DummyData::new("I'm different!", 0);
-            let dummy_data_clone = dummy_data.clone();
+            assert_eq!(oda.fetch_max(9), Some(5));
+            assert_eq!(oda.fetch_min(-1), Some(9));
+            assert_eq!(oda.copy_value(), Some(-1));
 
-            // Reference to data before the mutation.
-            let before_mutate = data_access_clone.get();
-            // Will be re-assigned to the value set during the mutate closure.
-            let mut set_value = Arc::new(DummyData::default());
+            oda.take();
+            assert_eq!(oda.fetch_max(1), None);
+            assert_eq!(oda.fetch_min(1), None);
+            assert!(oda.get().is_none());
+        }
 
-            // Starts mutation
-            data_access.mutate(|mut data| {
-                data.num += 1;
-                assert_eq!(*data_access.get(), DummyData::default());
+        #[test]
+        /// The values must be exchanged, with references to the old values remaining valid.
+        fn swap_with() {
+            let first = Oda::new(Data::new(1));
+            let second = Oda::default();
+            let first_before = first.get().unwrap();
 
-                // Reads & modifies the value during the mutation.
-                {
-                    let during_mutate = data_access_clone.get();
+            first.swap_with(&second);
+            assert!(first.get().is_none());
+            assert!(Arc::ptr_eq(&second.get().unwrap(), &first_before));
 
-                    assert_eq!(*before_mutate, *during_mutate);
+            second.swap_with(&first);
+            assert_eq!(*first.get().unwrap(), 1.into());
+            assert!(second.get().is_none());
 
-                    data_access_clone.set(dummy_data_clone.clone());
-                    assert_eq!(*data_access_clone.get(), dummy_data_clone);
+            // Swapping with a clone has no effect.
+            first.swap_with(&first.clone());
+            assert_eq!(*first.get().unwrap(), 1.into());
+            assert_eq!(*first_before, 1.into());
+        }
 
-                    set_value = data_access_clone.get();
-                }
+        #[test]
+        #[allow(deprecated)]
+        fn empty() {
+            let oda = Oda::new(Data::default());
+            assert_eq!(*oda.get().unwrap(), Data::default());
 
-                // The value should be the modified one
-                assert_eq!(*data_access.get(), dummy_data);
-                // Finishes the mutation.
-                data
-            });
+            let empty = oda.empty();
+            assert_eq!(*empty.unwrap(), Data::default());
 
-            // The value now will be the mutation value, as it was the last process to modify the Da.
-            assert_eq!(*data_access.get(), DummyData::new("", 1));
-            // The pointer to the value set during the mutation will still be valid.
-            assert_eq!(*set_value, dummy_data);
+            assert!(oda.get().is_none());
         }
 
         #[test]
         fn copy_value() {
-            let da = Da::new(5);
-            let copied_value = da.copy_value();
+            let oda = Oda::new(5);
+            let copied_value = oda.copy_value().unwrap();
 
-            assert_eq!(*da.get(), copied_value);
+            assert_eq!(*oda.get().unwrap(), copied_value);
             assert_eq!(copied_value, 5);
 
-            da.mutate(|val| val + 1);
+            oda.mutate(|val| val + 1);
+            assert_ne!(*oda.get().unwrap(), copied_value);
 
-            assert_ne!(*da.get(), copied_value);
+            oda.take();
+            assert!(oda.copy_value().is_none());
         }
 
         #[test]
         /// The previous value must be returned.
         fn returns_old_data() {
-            let da = Da::new(DummyData::default());
+            let da = Oda::new(DummyData::default());
             let set = da.set(DummyData::new("a", 1));
-            assert_eq!(DummyData::default(), *set);
+            assert_eq!(DummyData::default(), *set.unwrap());
         }
-    }
 
-    #[cfg(test)]
-    mod optional_data_access {
-        use std::thread;
+        #[test]
+        fn map_or() {
+            let oda = Oda::new(Data::new(5));
+            assert_eq!(oda.map_or(0, |value| value.clone().into()), 5);
 
-        use crate::test_data::Data;
+            let oda: Oda<Data> = Oda::default();
+            assert_eq!(oda.map_or(0, |value| value.clone().into()), 0);
+        }
 
-        use super::*;
+        #[test]
+        fn map_or_else() {
+            let oda = Oda::new(Data::new(5));
+            let mapped: i32 =
+                oda.map_or_else(|| panic!("Value is present"), |value| value.clone().into());
+            assert_eq!(mapped, 5);
 
-        fn get_default() -> Oda<DummyData> {
-            Oda::new(DummyData::default())
+            let oda: Oda<Data> = Oda::default();
+            let mapped = oda.map_or_else(|| 0, |_| panic!("Value is absent"));
+            assert_eq!(mapped, 0);
         }
 
         #[test]
-        fn default_to_none() {
-            let oda: Oda<DummyData> = Oda::default();
+        fn or_else() {
+            let oda = Oda::new(Data::new(5));
+            let value = oda.or_else(|| panic!("Value is present"));
+            assert_eq!(*value.unwrap(), 5.into());
+
+            let oda: Oda<Data> = Oda::default();
+            assert!(oda.or_else(|| None).is_none());
+            assert_eq!(*oda.or_else(|| Some(Arc::new(1.into()))).unwrap(), 1.into());
+            // The underlying data is unchanged.
             assert!(oda.get().is_none());
         }
 
         #[test]
-        fn holds_data() {
-            let data_access = Oda::new(DummyData::default());
-            assert_eq!(*data_access.get().unwrap(), DummyData::default());
+        fn unwrap_or_else() {
+            let oda = Oda::new(Data::new(5));
+            let value = oda.unwrap_or_else(|| panic!("Value is present"));
+            assert_eq!(*value, 5.into());
+
+            let oda: Oda<Data> = Oda::default();
+            assert_eq!(*oda.unwrap_or_else(|| Arc::new(1.into())), 1.into());
+            assert!(oda.get().is_none());
         }
 
         #[test]
-        fn mutate_data() {
-            let data_access = get_default();
-            let mutated_dummy = DummyData::new("I'm different!", 0);
+        fn get_or_else() {
+            let oda = Oda::new(Data::new(5));
+            assert_eq!(*oda.get_or_else(|| panic!("Value is present")), 5.into());
 
-            data_access.set(DummyData::new("I'm different!", 0));
-            assert_eq!(*data_access.get().unwrap(), mutated_dummy);
+            let oda: Oda<Data> = Oda::default();
+            assert_eq!(*oda.get_or_else(|| 1.into()), 1.into());
+            let fallback = Arc::new(Data::new(2));
+            assert!(Arc::ptr_eq(
+                &oda.get_or_else_arc(|| fallback.clone()),
+                &fallback
+            ));
+            // The underlying data is unchanged.
+            assert!(oda.get().is_none());
         }
 
         #[test]
-        fn old_reference() {
-            let data_access = get_default();
-            let mutated_dummy = DummyData::new("I'm different!", 0);
+        /// The lock must not be held whilst the given function executes.
+        fn combinators_release_lock() {
+            let oda = Oda::new(Data::new(5));
+            oda.map_or(0, |_| {
+                oda.set(1.into());
+                0
+            });
+            assert_eq!(*oda.get().unwrap(), 1.into());
 
-            // Get a reference to the original data
-            let initial_content = data_access.get().unwrap();
+            let oda: Oda<Data> = Oda::default();
+            oda.unwrap_or_else(|| {
+                oda.set(2.into());
+                Arc::new(0.into())
+            });
+            assert_eq!(*oda.get().unwrap(), 2.into());
+        }
+    }
 
-            // Set new data
-            data_access.set(DummyData::new("I'm different!", 0));
+    #[cfg(test)]
+    mod toggle {
+        use std::thread;
 
-            assert_eq!(*initial_content, DummyData::default());
-            assert_eq!(*data_access.get().unwrap(), mutated_dummy);
+        use super::*;
+
+        #[test]
+        fn toggles() {
+            let da = Da::new(false);
+
+            da.toggle();
+            assert!(da.copy_value());
+            da.toggle();
+            assert!(!da.copy_value());
+
+            // An even amount of toggles returns to the initial state.
+            (0..10).for_each(|_| da.toggle());
+            assert!(!da.copy_value());
         }
 
         #[test]
-        fn concurrency() {
-            let data_access = get_default();
-            let data_access_clone = data_access.clone();
+        fn optional_toggles() {
+            let oda = Oda::new(true);
+            oda.toggle();
+            assert_eq!(oda.copy_value(), Some(false));
 
-            let dummy_data = DummyData::new("I'm different!", 0);
-            let dummy_data_clone = DummyData::new("I'm different!", 0);
+            // Toggling no data has no effect.
+            oda.take();
+            oda.toggle();
+            assert!(oda.get().is_none());
+        }
 
-            // Get a reference to the original data
-            let initial_content = data_access.get().unwrap();
+        #[test]
+        /// Concurrent toggles must never be lost.
+        fn concurrent() {
+            let da = Da::new(false);
 
-            thread::spawn(move || {
-                assert_eq!(*data_access.get().unwrap(), DummyData::default());
+            let handles: Vec<_> = (0..2)
+                .map(|_| {
+                    let da = da.clone();
+                    thread::spawn(move || (0..1001).for_each(|_| da.toggle()))
+                })
+                .collect();
 
-                let mutated_dummy = dummy_data;
-                data_access.set(mutated_dummy.clone());
+            for handle in handles {
+                handle.join().unwrap();
+            }
 
-                assert_eq!(*data_access.get().unwrap(), mutated_dummy);
-            })
-            .join()
-            .unwrap();
+            // 2002 toggles in total.
+            assert!(!da.copy_value());
+        }
+    }
 
-            // Original reference still valid.
-            assert_eq!(*initial_content, DummyData::default());
-            // New references reference new value.
-            assert_eq!(*data_access_clone.get().unwrap(), dummy_data_clone);
+    mod on_set {
+        use super::*;
+        use crate::locking_mutate;
+        use std::{
+            panic::{catch_unwind, AssertUnwindSafe},
+            sync::mpsc,
+        };
 
-            // Arc will get freed after initial_count is dropped.
-            assert_eq!(Arc::weak_count(&initial_content), 0);
-            assert_eq!(Arc::strong_count(&initial_content), 1);
+        #[test]
+        fn called_on_mutation() {
+            let da = Da::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let _handle = da.on_set(move |value| sender.send(*value).unwrap());
+
+            da.set(2);
+            da.set_arc(Arc::new(3));
+            da.mutate(|value| value + 1);
+
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
         }
 
         #[test]
-        fn race_condition() {
-            let data_access = get_default();
-            let data_access_clone = data_access.clone();
+        /// Every method which changes the data must call the callbacks, except `locking_mutate!`.
+        fn called_on_every_change() {
+            let mut da = Da::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let _handle = da.on_set(move |value| sender.send(*value).unwrap());
 
-            let dummy_data = DummyData::new("I'm different!", 0);
-            let dummy_data_clone = dummy_data.clone();
+            da.try_set(2).unwrap();
+            // Setting an equal value makes no change.
+            da.conditional_set(2);
+            da.conditional_set(3);
+            da.copy_and_set(4);
+            da.fetch_add(1);
+            da.fetch_max(10);
+            da.mutate_with_previous(|value| value + 1);
+            da.mutate_returning(|value| (value + 1, ()));
+            da.write_with(|value| *value += 1);
+            da.mutate_in_place(|value| *value += 1);
+            da += 1;
+            da.take();
+            da.swap_with(&Da::new(20));
+            da.merge_with(&Da::new(1), |value, other| value + other);
+            Da::fanout(&[&da], 30);
 
-            // Reference to data before the mutation.
-            let before_mutate = data_access_clone.get().unwrap();
-            // Will be re-assigned to the value set during the mutate closure.
-            let mut set_value = Arc::new(DummyData::default());
+            locking_mutate!(da; |value| value + 1);
 
-            // Starts mutation
-            data_access.mutate(|mut data| {
-                data.num += 1;
-                assert_eq!(*data_access.get().unwrap(), DummyData::default());
+            assert_eq!(
+                receiver.try_iter().collect::<Vec<_>>(),
+                vec![2, 3, 4, 5, 10, 11, 12, 13, 14, 15, 0, 20, 21, 30]
+            );
+        }
 
-                // Reads & modifies the value during the mutation.
-                {
-                    let during_mutate = data_access_clone.get().unwrap();
+        #[test]
+        /// The callbacks are only allocated when needed, but must still be shared with clones made
+        /// before the first callback is registered.
+        fn allocated_lazily() {
+            let da = Da::new(1);
+            da.set(2);
+            assert!(da.callbacks.get().is_none());
 
-                    assert_eq!(*before_mutate, *during_mutate);
+            let clone = da.clone();
+            assert!(da.callbacks.get().is_some());
 
-                    data_access_clone.set(dummy_data_clone.clone());
-                    assert_eq!(*data_access_clone.get().unwrap(), dummy_data_clone);
+            let (sender, receiver) = mpsc::channel();
+            let handle = da.on_set(move |value| sender.send(*value).unwrap());
+            clone.set(3);
+            drop(handle);
+            clone.set(4);
 
-                    set_value = data_access_clone.get().unwrap();
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![3]);
+            assert!(da.callbacks.shared().is_empty());
+        }
+
+        #[test]
+        fn called_on_collection_change() {
+            let da = Da::new(vec![1]);
+            let (sender, receiver) = mpsc::channel();
+            let _handle = da.on_set(move |value| sender.send(value.len()).unwrap());
+
+            da.push_into(2);
+            da.pop_from();
+            da.pop_from();
+            // Popping from an empty `Vec` makes no change.
+            da.pop_from();
+
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2, 1, 0]);
+        }
+
+        #[test]
+        /// A callback may deregister itself or register another callback, without deadlocking.
+        fn callback_changes_callbacks() {
+            let da = Da::new(0);
+            let calls = Arc::new(Mutex::new(0));
+
+            let own_handle = Arc::new(Mutex::new(None));
+            *acquire_lock(&own_handle) = Some(da.on_set({
+                let (own_handle, calls) = (own_handle.clone(), calls.clone());
+                move |_| {
+                    *acquire_lock(&calls) += 1;
+                    acquire_lock(&own_handle).take();
                 }
+            }));
 
-                // The value should be the modified one
-                assert_eq!(*data_access.get().unwrap(), dummy_data);
-                // Finishes the mutation.
-                data
+            let registered = Arc::new(Mutex::new(Vec::new()));
+            let _handle = da.on_set({
+                let (da, registered, calls) = (da.clone(), registered.clone(), calls.clone());
+                move |_| {
+                    let calls = calls.clone();
+                    let handle = da.on_set(move |_| *acquire_lock(&calls) += 10);
+                    acquire_lock(&registered).push(handle);
+                }
             });
 
-            // The value now will be the mutation value, as it was the last process to modify the Da.
-            assert_eq!(*data_access.get().unwrap(), DummyData::new("", 1));
-            // The pointer to the value set during the mutation will still be valid.
-            assert_eq!(*set_value, dummy_data);
+            da.set(1);
+            da.set(2);
+
+            // The first callback is called once, then the callback registered by the first change.
+            assert_eq!(*acquire_lock(&calls), 11);
+            assert_eq!(acquire_lock(&registered).len(), 2);
         }
 
         #[test]
-        fn acquire() {
-            let original = Oda::new(DummyData::new("A!", 0));
-            let acquired = Oda::acquire(original.get().unwrap());
+        /// A panicking callback mustn't poison the data or the other callbacks.
+        fn callback_panics() {
+            let da = Da::new(0);
+            let _handle = da.on_set(|value| assert_ne!(*value, 1, "Callback failed"));
+            let (sender, receiver) = mpsc::channel();
+            let _other_handle = da.on_set(move |value| sender.send(*value).unwrap());
 
-            assert_eq!(original.get(), acquired.get());
+            assert!(catch_unwind(AssertUnwindSafe(|| da.set(1))).is_err());
+            // The change is made before the callbacks are called.
+            assert_eq!(*da.get(), 1);
 
-            // original has a new internal arc.
-            original.set(DummyData::default());
+            assert!(da.try_set(2).is_ok());
+            da.mutate(|value| value + 1);
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2, 3]);
+        }
 
-            assert_eq!((*acquired.get().unwrap()), DummyData::new("A!", 0));
-            assert_eq!(*original.get().unwrap(), DummyData::default());
+        #[test]
+        fn not_called_on_read() {
+            let da = Da::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let _handle = da.on_set(move |value| sender.send(*value).unwrap());
+
+            da.get();
+            da.copy_value();
+            da.inspect(|_| {});
+
+            assert!(receiver.try_recv().is_err());
         }
 
         #[test]
-        fn replace() {
-            let original = Oda::new(DummyData::new("A!", 0));
+        /// Callbacks are shared between clones & may read the data they were called for.
+        fn called_from_clone() {
+            let da = Da::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let da_clone = da.clone();
+            let _handle = da.on_set(move |value| {
+                assert_eq!(value, da_clone.get());
+                sender.send(*value).unwrap();
+            });
 
-            let to_replace = Oda::default();
-            to_replace.replace(Some(original.get().unwrap()));
+            da.clone().set(2);
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2]);
+        }
 
-            assert_eq!(original.get(), to_replace.get());
+        #[test]
+        fn deregistered_on_drop() {
+            let da = Da::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let handle = da.on_set(move |value| sender.send(*value).unwrap());
 
-            // original has a new internal arc.
-            original.set(DummyData::default());
+            da.set(2);
+            drop(handle);
+            da.set(3);
 
-            assert_eq!((*to_replace.get().unwrap()), DummyData::new("A!", 0));
-            assert_eq!(*original.get().unwrap(), DummyData::default());
+            assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2]);
         }
 
         #[test]
-        fn empty() {
-            let oda = Oda::new(Data::default());
-            assert_eq!(*oda.get().unwrap(), Data::default());
+        fn optional() {
+            let oda = Oda::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let _handle = oda.on_set(move |value| sender.send(value.as_deref().copied()).unwrap());
 
-            let empty = oda.empty();
-            assert_eq!(*empty.unwrap(), Data::default());
+            oda.set(2);
+            oda.mutate(|value| value + 1);
+            oda.set_arc(None);
+            // Mutating no data has no effect.
+            oda.mutate(|value| value + 1);
+            oda.get();
 
-            assert!(oda.get().is_none());
+            assert_eq!(
+                receiver.try_iter().collect::<Vec<_>>(),
+                vec![Some(2), Some(3), None]
+            );
         }
 
         #[test]
-        fn copy_value() {
-            let oda = Oda::new(5);
-            let copied_value = oda.copy_value().unwrap();
+        fn optional_called_on_every_change() {
+            let oda = Oda::new(1);
+            let (sender, receiver) = mpsc::channel();
+            let _handle = oda.on_set(move |value| sender.send(value.as_deref().copied()).unwrap());
 
-            assert_eq!(*oda.get().unwrap(), copied_value);
-            assert_eq!(copied_value, 5);
+            oda.try_set(2).unwrap();
+            oda.conditional_set(Some(2));
+            oda.conditional_set(Some(3));
+            oda.copy_and_set(Some(4));
+            oda.fetch_add(1);
+            oda.mutate_with_previous(|value| value + 1);
+            oda.mutate_returning(|value| (value.map(|value| value + 1), ()));
+            oda.take();
+            // Changing no data has no effect.
+            oda.fetch_add(1);
+            oda.swap_with(&Oda::new(20));
+            oda.merge_with(&Oda::new(1), |value, other| {
+                value.zip(other).map(|(a, b)| a + b)
+            });
 
-            oda.mutate(|val| val + 1);
-            assert_ne!(*oda.get().unwrap(), copied_value);
+            assert_eq!(
+                receiver.try_iter().collect::<Vec<_>>(),
+                vec![
+                    Some(2),
+                    Some(3),
+                    Some(4),
+                    Some(5),
+                    Some(6),
+                    Some(7),
+                    None,
+                    Some(20),
+                    Some(21)
+                ]
+            );
+        }
 
-            oda.empty();
-            assert!(oda.copy_value().is_none());
+        #[test]
+        fn scan() {
+            let da = Da::new(0u64);
+            let sum = da.scan(0, |sum, value| sum + value);
+            let max = da.scan(0, |max: u64, value| max.max(*value));
+
+            for value in [3, 7, 1, 9, 4, 4, 2, 8, 5, 6] {
+                da.set(value);
+            }
+
+            assert_eq!(sum.copy_value(), 49);
+            assert_eq!(max.copy_value(), 9);
         }
 
         #[test]
-        /// The previous value must be returned.
-        fn returns_old_data() {
-            let da = Oda::new(DummyData::default());
-            let set = da.set(DummyData::new("a", 1));
-            assert_eq!(DummyData::default(), *set.unwrap());
+        /// Changes made by methods other than `set` must also update the aggregate.
+        fn scan_other_changes() {
+            let mut da = Da::new(0);
+            let sum = da.scan(0, |sum, value| sum + value);
+
+            da += 1;
+            da.fetch_add(2);
+            da.mutate_in_place(|value| *value += 3);
+            da.take();
+            assert_eq!(sum.copy_value(), 1 + 3 + 6);
+        }
+
+        #[test]
+        /// Scans may be chained, as the scanned `Da` calls its own callbacks.
+        fn scan_chained() {
+            let da = Da::new(0);
+            let count = da.scan(0, |count, _| count + 1);
+            let doubled = count.scan(0, |_, count| count * 2);
+
+            da.set(1);
+            da.mutate(|value| value + 1);
+            assert_eq!(doubled.copy_value(), 4);
+        }
+
+        #[test]
+        fn scan_deregistered_on_drop() {
+            let da = Da::new(0);
+            let sum = da.scan(0, |sum, value| sum + value);
+            let sum_clone = sum.clone();
+
+            da.set(1);
+            drop(sum);
+            da.set(2);
+            assert_eq!(sum_clone.copy_value(), 3);
+
+            drop(sum_clone);
+            da.set(3);
+            assert!(da.callbacks.shared().is_empty());
         }
     }
 }
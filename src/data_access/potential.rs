@@ -0,0 +1,180 @@
+//! Contains the [`PotentialDa`], a [`Da`] which may not have been given a value yet.
+
+// Used in docs
+#[allow(unused_imports)]
+use crate::data_access::Da;
+
+use crate::data_access::Oda;
+use crate::sync::Arc;
+use core::fmt::Debug;
+
+/// The message used when panicking due to accessing a [`PotentialDa`] before it has been set.
+const UNSET_MESSAGE: &str = "The PotentialDa was accessed before being set";
+
+/// [`PotentialDataAccess`](PotentialDa)
+/// ---
+///
+/// A [`Da`] which starts without a value, for when the value can't be decided upon construction.
+///
+/// Accessing the value before it has been [`set`](Self::set()) will panic, with
+/// [`try_get`](Self::try_get()) being available as a non-panicking alternative. Once set, it
+/// behaves the same as a [`Da`].
+pub struct PotentialDa<Value>
+where
+    Value: 'static,
+{
+    /// Contains the data being represented, which is `None` until set.
+    data: Oda<Value>,
+}
+
+impl<Value> PotentialDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`PotentialDa<Value>`] which has already been set to the given value.
+    pub fn new(data: Value) -> Self {
+        Self {
+            data: Oda::new(data),
+        }
+    }
+
+    /// Creates a new [`PotentialDa<Value>`] without a value.
+    pub fn unset() -> Self {
+        Self {
+            data: Oda::default(),
+        }
+    }
+
+    /// Returns `true` if a value has been set.
+    pub fn is_set(&self) -> bool {
+        self.data.get().is_some()
+    }
+
+    /// Gets a reference to the current underlying data.
+    ///
+    /// See [`Da::get()`] for more information.
+    ///
+    /// # Panics
+    /// Panics if a value hasn't been set yet.
+    pub fn get(&self) -> Arc<Value> {
+        self.try_get().expect(UNSET_MESSAGE)
+    }
+
+    /// Gets a reference to the current underlying data, or `None` if a value hasn't been set yet.
+    pub fn try_get(&self) -> Option<Arc<Value>> {
+        self.data.get()
+    }
+
+    /// Allows for a value that implements [`Copy`] to be copied out of [`PotentialDa`].
+    ///
+    /// # Panics
+    /// Panics if a value hasn't been set yet.
+    pub fn copy_value(&self) -> Value
+    where
+        Value: Copy,
+    {
+        self.data.copy_value().expect(UNSET_MESSAGE)
+    }
+
+    /// Creates new underlying data with the given value; Returning the old value, or `None` if a
+    /// value hadn't been set yet.
+    ///
+    /// See [`Da::set()`] for more information on the behaviour of current & future references.
+    pub fn set(&self, new_data: Value) -> Option<Arc<Value>> {
+        self.data.set(new_data)
+    }
+
+    /// Clones the existing underlying data & calls the given function with the clone as the
+    /// parameter. The value returned from the function will be set as the new underlying data.
+    ///
+    /// See [`Da::mutate()`] for more information, including the locking behaviour.
+    ///
+    /// # Panics
+    /// Panics if a value hasn't been set yet.
+    pub fn mutate<Func>(&self, func: Func)
+    where
+        Value: Clone,
+        Func: FnOnce(Value) -> Value,
+    {
+        let mutated_value = func((*self.get()).clone());
+        self.set(mutated_value);
+    }
+}
+
+impl<Value> Default for PotentialDa<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::unset()`].
+    fn default() -> Self {
+        Self::unset()
+    }
+}
+
+impl<Value> From<Value> for PotentialDa<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new(value)`](Self::new()).
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Value> Clone for PotentialDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new [`PotentialDa`] pointing to the **exact same** value as the original
+    /// [`PotentialDa`].
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<Value> Debug for PotentialDa<Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PotentialDa")
+            .field("current_ref", &self.try_get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::Data;
+
+    #[test]
+    fn unset_until_set() {
+        let potential_da: PotentialDa<Data> = PotentialDa::default();
+        assert!(!potential_da.is_set());
+        assert!(potential_da.try_get().is_none());
+
+        // Clones share the same data, so can be set after being handed out.
+        let clone = potential_da.clone();
+        assert!(clone.set(Data::new(1)).is_none());
+
+        assert!(potential_da.is_set());
+        assert_eq!(*potential_da.get(), 1.into());
+        assert_eq!(*potential_da.set(Data::new(2)).unwrap(), 1.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "The PotentialDa was accessed before being set")]
+    fn get_unset_panics() {
+        PotentialDa::<Data>::unset().get();
+    }
+
+    #[test]
+    fn mutate() {
+        let potential_da = PotentialDa::new(1);
+        potential_da.mutate(|value| value + 1);
+        assert_eq!(potential_da.copy_value(), 2);
+    }
+}
@@ -0,0 +1,105 @@
+//! Contains [`ProjectedDa`], which provides a live read-only view of part of a [`Da`].
+
+use crate::data_access::Da;
+use crate::sync::Arc;
+use core::fmt::Debug;
+
+/// The function reading the projected value from the source [`Da`].
+type Projection<Value> = Arc<dyn Fn() -> Arc<Value> + Send + Sync>;
+
+/// A read-only view of part of the underlying data of a [`Da`], created by [`Da::project()`].
+///
+/// The value is read from the source [`Da`] each time it's accessed, so changes to the source are
+/// immediately visible, unlike a snapshot from [`Da::map_arc()`]. Clones share the same source.
+pub struct ProjectedDa<Value>
+where
+    Value: 'static,
+{
+    projection: Projection<Value>,
+}
+
+impl<Value> ProjectedDa<Value>
+where
+    Value: Clone + 'static,
+{
+    /// Creates a view of the value returned by the extractor for the underlying data of the given
+    /// [`Da`].
+    pub(super) fn new<Source, Func>(source: Da<Source>, extractor: Func) -> Self
+    where
+        Source: Send + Sync + 'static,
+        Func: Fn(&Source) -> &Value + Send + Sync + 'static,
+    {
+        Self {
+            projection: Arc::new(move || Arc::new(extractor(&source.get()).clone())),
+        }
+    }
+
+    /// Returns a clone of the projected value from the current underlying data of the source.
+    ///
+    /// The lock on the source isn't held whilst the value is being cloned.
+    pub fn get(&self) -> Arc<Value> {
+        (self.projection)()
+    }
+
+    /// Returns a clone of the projected value, without wrapping it in an [`Arc`].
+    pub fn get_cloned(&self) -> Value {
+        Arc::unwrap_or_clone(self.get())
+    }
+}
+
+impl<Value> Clone for ProjectedDa<Value>
+where
+    Value: 'static,
+{
+    /// Creates a new view sharing the same source & extractor.
+    fn clone(&self) -> Self {
+        Self {
+            projection: self.projection.clone(),
+        }
+    }
+}
+
+impl<Value> Debug for ProjectedDa<Value>
+where
+    Value: Clone + Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ProjectedDa").field(&self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[derive(Clone, Debug)]
+    struct Config {
+        name: String,
+        retries: u8,
+    }
+
+    #[test]
+    /// Changes to the source must be visible through the view, including through clones.
+    fn project() {
+        let config = Da::new(Config {
+            name: "first".into(),
+            retries: 1,
+        });
+        let name = config.project(|config| &config.name);
+        let clone = name.clone();
+        assert_eq!(*name.get(), "first");
+
+        config.mutate(|config| Config {
+            name: "second".into(),
+            ..config
+        });
+        assert_eq!(name.get_cloned(), "second");
+        assert_eq!(*clone.get(), "second");
+        assert_eq!(config.get().retries, 1);
+
+        // The view keeps the source alive.
+        drop(config);
+        assert_eq!(format!("{name:?}"), r#"ProjectedDa("second")"#);
+    }
+}
@@ -0,0 +1,81 @@
+//! Contains [`UniqueValue`], which provides exclusive ownership of the underlying data of a [`Da`].
+
+use crate::data_access::{callbacks::LazyCallbacks, Da};
+use crate::sync::{Arc, Mutex};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+/// Provides mutable access to the underlying data of a [`Da`], which had no other references.
+///
+/// Created by [`Da::try_into_unique()`]. The data can be mutated in place without being cloned,
+/// then converted back into a [`Da`] through [`Self::into_da()`]. As no other references exist,
+/// dropping this drops the underlying data, rather than wrapping it back into a [`Da`], as there
+/// would be nothing left to return the new [`Da`] to.
+#[must_use = "The underlying data is dropped with this, see `into_da()` to keep it"]
+pub struct UniqueValue<Value>
+where
+    Value: 'static,
+{
+    pub(super) value: Value,
+    /// The callbacks of the original [`Da`], which are restored by [`Self::into_da()`].
+    pub(super) callbacks: LazyCallbacks<Arc<Value>>,
+}
+
+impl<Value> UniqueValue<Value>
+where
+    Value: 'static,
+{
+    /// Wraps the value back into a new [`Da`], keeping any callbacks registered on the original.
+    pub fn into_da(self) -> Da<Value> {
+        Da {
+            current_ref: Arc::new(Mutex::new(Arc::new(self.value))),
+            callbacks: self.callbacks,
+        }
+    }
+
+    /// Returns the owned underlying data.
+    pub fn into_inner(self) -> Value {
+        self.value
+    }
+}
+
+impl<Value> From<UniqueValue<Value>> for Da<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`UniqueValue::into_da()`].
+    fn from(unique: UniqueValue<Value>) -> Self {
+        unique.into_da()
+    }
+}
+
+impl<Value> Deref for UniqueValue<Value>
+where
+    Value: 'static,
+{
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<Value> DerefMut for UniqueValue<Value>
+where
+    Value: 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<Value> Debug for UniqueValue<Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("UniqueValue").field(&**self).finish()
+    }
+}
@@ -1,17 +1,60 @@
-use std::{fmt::Debug, hash::Hash, sync::Arc};
+use std::{collections::HashMap, fmt::Debug, hash::Hash, marker::PhantomData, sync::Arc};
 
-use crate::data_access::{Da, Oda};
+use crate::data_access::{locking_mutate::Lock, Da, Oda};
 
+/// The amount of buckets a [`CellHashMap`] is created with by default.
 const DEFAULT_MAX_SIZE: u64 = 256;
 
+/// The root of a chain of entries whose keys share the same position.
+type Bucket<Key, Value> = Oda<CellEntry<Key, Value>>;
+
+/// A hash map, which can be read from & written to "concurrently".
+///
+/// [`Index`](std::ops::Index) isn't implemented, as it must return a reference which outlives the
+/// lock on the entry, allowing the value to be replaced whilst it's borrowed. Use
+/// [`Self::get()`] or [`Self::get_or_default()`] instead.
+///
+/// [`IndexMut`](std::ops::IndexMut) is intentionally not implemented either, as mutating a value
+/// in place would change it for every existing reference to it. Use [`Self::update()`] or
+/// [`Self::update_or_insert()`] instead.
 pub struct CellHashMap<Key, Value>
 where
     Key: Debug + Hash + Clone + Eq + 'static,
     Value: Debug + Clone + 'static,
 {
-    // current_size: Da<usize>,
-    array: Da<[Oda<CellEntry<Key, Value>>; DEFAULT_MAX_SIZE as usize]>,
-    // data: Da<Contains<Key, Value>>,
+    /// The amount of entries currently in the map.
+    ///
+    /// Only updated whilst holding the lock on the bucket being modified, so it's consistent with
+    /// the entries once every modification has finished.
+    element_count: Da<usize>,
+    /// The buckets are never replaced, so the array doesn't need its own lock. Each bucket has its
+    /// own lock, which allows operations on different buckets to occur concurrently.
+    array: Arc<[Bucket<Key, Value>]>,
+}
+
+impl<Key, Value> Clone for CellHashMap<Key, Value>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+    Value: Debug + Clone + 'static,
+{
+    /// Creates a new [`CellHashMap`] pointing to the **exact same** entries as the original.
+    fn clone(&self) -> Self {
+        Self {
+            element_count: self.element_count.clone(),
+            array: self.array.clone(),
+        }
+    }
+}
+
+impl<Key, Value> Default for CellHashMap<Key, Value>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+    Value: Debug + Clone + 'static,
+{
+    /// Idiomatic to calling [`Self::new()`].
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<Key, Value> CellHashMap<Key, Value>
@@ -19,98 +62,436 @@ where
     Key: Debug + Hash + Clone + Eq + 'static,
     Value: Debug + Clone + 'static,
 {
+    /// Creates a new [`CellHashMap`] with the default amount of buckets.
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new [`CellHashMap`] with enough buckets to hold at least the given amount of
+    /// entries without them having to share buckets.
+    ///
+    /// The bucket count is never less than the default amount of buckets.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let buckets = capacity.next_power_of_two().max(DEFAULT_MAX_SIZE as usize);
+
         Self {
-            // data: Contains {
-            //     element_count: 0,
-            //     array: core::array::from_fn(|_| Oda::default()),
-            // }
-            // .into(),
-            // current_size: 0.into(),
-            array: core::array::from_fn(|_| Oda::default()).into(),
+            element_count: Da::new(0),
+            array: (0..buckets).map(|_| Oda::default()).collect(),
         }
     }
 
-    // fn array_get(&self, index: usize) -> Option<Arc<CellEntry<Key, Value>>> {
-    //     self.data.get().array[index].get()
-    // }
+    /// Returns the index of the bucket the given key belongs in.
+    fn bucket_index(&self, key: &Key) -> usize {
+        let buckets = self.array.len() as u64;
+        (hash_key(key) % buckets) as usize
+    }
 
-    // fn size_get(&self) -> usize {
-    //     self.data.get().element_count
-    // }
+    /// Returns the bucket the given key belongs in.
+    fn bucket(&self, key: &Key) -> &Bucket<Key, Value> {
+        &self.array[self.bucket_index(key)]
+    }
 
+    /// Inserts the given key-value pair into the map, returning the previous value for the key.
+    ///
+    /// The lock on the key's bucket is held whilst inserting, so concurrent insertions of the same
+    /// key can't both be treated as new. Insertions into other buckets can occur concurrently.
     pub fn put(&self, key: Key, value: Value) -> Option<Value> {
-        let hash_val: u64 = hash_key(key.clone());
-        let position = (hash_val % DEFAULT_MAX_SIZE) as usize;
-
-        let mut result = None;
+        let mut root = Lock::lock(self.bucket(&key));
 
-        match self.array.get().as_ref()[position].get() {
-            Some(entry) => result = entry.set(CellEntry::new(key, value)),
-            None => self.array.get().as_ref()[position].set(CellEntry::new(key, value)),
-        }
+        let result = match root.as_deref() {
+            Some(entry) => entry.set(CellEntry::new(key, value)),
+            None => {
+                *root = Some(Arc::new(CellEntry::new(key, value)));
+                None
+            }
+        };
 
         // If the result was none then a new value was added.
         if result.is_none() {
-            // self.current_size.mutate(|size| size + 1)
+            self.element_count.fetch_add(1);
         }
+
         result.map(|value| value.value.get().as_ref().clone())
     }
 
     pub fn get(&self, key: Key) -> Option<Arc<Value>> {
-        let hash_val: u64 = hash_key(key.clone());
-        let position = (hash_val % DEFAULT_MAX_SIZE) as usize;
-
-        match self.array.get().as_ref()[position].get() {
+        match self.bucket(&key).get() {
             Some(data) => data.get(&key).map(|cell_entry| cell_entry.value.get()),
             None => None,
         }
     }
 
-    pub fn remove(&self, key: Key) {
-        let hash_val: u64 = hash_key(key.clone());
-        let position = (hash_val % DEFAULT_MAX_SIZE) as usize;
+    /// Returns the value for each of the given keys, in the order they were given.
+    ///
+    /// The lock on every key's bucket is held whilst looking up all of the keys, so the values are
+    /// a consistent snapshot, unlike calling [`Self::get()`] for each key. The buckets are locked
+    /// in order of their position, so concurrent calls can't deadlock.
+    pub fn get_many<'a, Iter>(&self, keys: Iter) -> Vec<(Key, Option<Arc<Value>>)>
+    where
+        Iter: IntoIterator<Item = &'a Key>,
+        Key: 'a,
+    {
+        let keys: Vec<(usize, &Key)> = keys
+            .into_iter()
+            .map(|key| (self.bucket_index(key), key))
+            .collect();
 
-        let entry_iter = self.array.get().as_ref()[position].get().map(|root| {
-            // It makes the compiler happy.
-            (*root)
-                .clone()
-                // Removes the element if the key matches.
-                .filter(|entry| {
-                    println!("{:?}", entry.key);
-                    println!("{:?}", key);
-                    entry.key != key
-                })
-        });
+        let mut indices: Vec<usize> = keys.iter().map(|(index, _)| *index).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let roots: Vec<_> = indices
+            .iter()
+            .map(|index| Lock::lock(&self.array[*index]))
+            .collect();
+
+        keys.into_iter()
+            .map(|(index, key)| {
+                let root = &roots[indices
+                    .binary_search(&index)
+                    .expect("Every bucket is locked")];
+                let value = root
+                    .as_deref()
+                    .and_then(|root| root.get(key))
+                    .map(|entry| entry.value.get());
+                (key.clone(), value)
+            })
+            .collect()
+    }
 
-        println!("{:#?}", entry_iter);
+    /// Returns the value for the given key, or the default value if the key isn't present.
+    ///
+    /// The default value isn't inserted into the map.
+    pub fn get_or_default(&self, key: &Key) -> Arc<Value>
+    where
+        Value: Default,
+    {
+        self.get(key.clone()).unwrap_or_default()
+    }
 
-        if let Some(mut iter) = entry_iter {
-            if let Some(mut root) = iter.next() {
-                let mut collection = Vec::new();
-                for entry in iter {
-                    // println!("\n\nentry: {:?}\n\n", entry);
-                    collection.push(entry);
+    /// Returns `true` if the map contains a value for the given key.
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.bucket(key)
+            .get()
+            .is_some_and(|root| root.get(key).is_some())
+    }
+
+    /// Removes the given key from the map, returning its value if it was present.
+    ///
+    /// The value is cloned out of the entry, unless no other references to it remain. The lock on
+    /// the key's bucket is held whilst removing.
+    pub fn remove(&self, key: Key) -> Option<Value> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes the given key from the map, returning the stored key & its value if it was present.
+    ///
+    /// See [`Self::remove()`] for when the value is cloned.
+    pub fn remove_entry(&self, key: Key) -> Option<(Key, Value)> {
+        let mut root = Lock::lock(self.bucket(&key));
+
+        let mut previous: Option<Arc<CellEntry<Key, Value>>> = None;
+        let mut current = root.clone();
+
+        while let Some(entry) = current {
+            if entry.has_key(&key) {
+                // Unlinks the entry by pointing the previous link in the chain to the next entry.
+                let next = entry.next.get();
+                match previous {
+                    Some(previous) => {
+                        previous.next.set_arc(next);
+                    }
+                    None => *root = next,
                 }
-                collection.reverse();
 
-                // Line causing issue?
-                // let mut collect: Vec<Arc<CellEntry<Key, Value>>> = iter.collect();
-                // collect.reverse();
+                self.element_count.fetch_sub(1);
+                let (removed_key, value) = (entry.key.get_cloned(), entry.value.get());
+                // Drops the entry's reference to the value, so it may not need to be cloned.
+                drop(entry);
+                return Some((removed_key, Arc::unwrap_or_clone(value)));
+            }
 
-                // Re-creates the linked list.
-                for entry in collection {
-                    entry.next.replace(root);
-                    root = entry;
+            current = entry.next.get();
+            previous = Some(entry);
+        }
+
+        None
+    }
+
+    /// Sets the value for the given key to the value returned by the given function, which is called
+    /// with a clone of the current value. Returns `false` if the map doesn't contain the key.
+    ///
+    /// The lock on the key's bucket is held whilst updating, so no other modification through the
+    /// map can occur between reading & writing the value.
+    pub fn update<Func>(&self, key: &Key, func: Func) -> bool
+    where
+        Func: FnOnce(Value) -> Value,
+    {
+        let root = Lock::lock(self.bucket(key));
+
+        match root.as_ref().and_then(|root| root.get(key)) {
+            Some(entry) => {
+                entry.value.set(func(entry.value.get_cloned()));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the value for the given key to the value returned by the given function. The function
+    /// is called with a clone of the current value, or the given default if the map doesn't
+    /// contain the key.
+    ///
+    /// See [`Self::update()`] for the locking behaviour.
+    pub fn update_or_insert<Func>(&self, key: Key, default: Value, func: Func)
+    where
+        Func: FnOnce(Value) -> Value,
+    {
+        let mut root = Lock::lock(self.bucket(&key));
+
+        if let Some(entry) = root.as_ref().and_then(|root| root.get(&key)) {
+            entry.value.set(func(entry.value.get_cloned()));
+            return;
+        }
+
+        let entry = CellEntry::new(key, func(default));
+        match root.as_deref() {
+            Some(root) => {
+                root.set(entry);
+            }
+            None => *root = Some(Arc::new(entry)),
+        }
+        self.element_count.fetch_add(1);
+    }
+
+    /// Removes every entry the given function returns `false` for.
+    ///
+    /// Each bucket is locked whilst its entries are being checked, so the function should be kept
+    /// short & **must not** access this map.
+    pub fn retain<Func>(&self, func: Func)
+    where
+        Func: Fn(&Key, &Value) -> bool,
+    {
+        for bucket in self.array.iter() {
+            let mut root = Lock::lock(bucket);
+
+            let mut previous: Option<Arc<CellEntry<Key, Value>>> = None;
+            let mut current = root.clone();
+            let mut removed = 0;
+
+            while let Some(entry) = current {
+                let next = entry.next.get();
+
+                if func(&entry.key.get(), &entry.value.get()) {
+                    previous = Some(entry);
+                } else {
+                    match &previous {
+                        Some(previous) => {
+                            previous.next.set_arc(next.clone());
+                        }
+                        None => *root = next.clone(),
+                    }
+                    removed += 1;
                 }
 
-                // Updates the array
-                self.array.mutate(|array| {
-                    array[position].replace(root);
-                    array
+                current = next;
+            }
+
+            if removed > 0 {
+                self.element_count.fetch_sub(removed);
+            }
+        }
+    }
+
+    /// Removes every entry from the map, as a single operation.
+    ///
+    /// The lock on every bucket is held whilst emptying them & resetting the element count to 0, so
+    /// a concurrent insertion or removal happens either entirely before or entirely after the map
+    /// is cleared. The buckets are locked in order of their position, in the same way as
+    /// [`Self::get_many()`], so concurrent calls can't deadlock.
+    ///
+    /// The buckets are emptied in place, rather than replacing the array, as the array is shared
+    /// between clones of the map.
+    pub fn clear(&self) {
+        let mut roots: Vec<_> = self.array.iter().map(Lock::lock).collect();
+
+        for root in roots.iter_mut() {
+            root.take();
+        }
+        self.element_count.set(0);
+    }
+
+    /// Returns the amount of entries in the map.
+    pub fn len(&self) -> usize {
+        self.element_count.copy_value()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over every key-value pair in the map, in no particular order.
+    ///
+    /// Both the keys & values are shared with the map rather than cloned. The entries are read
+    /// lazily, with each bucket being read as the iterator reaches it. So entries modified
+    /// concurrently may or may not be included.
+    pub fn iter(&self) -> CellHashMapIter<'_, Key, Value> {
+        CellHashMapIter {
+            array: self.array.clone(),
+            index: 0,
+            current: None,
+            map: PhantomData,
+        }
+    }
+
+    /// Returns a snapshot of every key-value pair currently in the map, in no particular order.
+    ///
+    /// Each bucket is read separately, so entries modified concurrently may or may not be included.
+    pub fn collect_entries(&self) -> Vec<(Key, Value)> {
+        let mut entries = Vec::new();
+
+        for bucket in self.array.iter() {
+            let mut current = bucket.get();
+            while let Some(entry) = current {
+                entries.push((entry.key.get_cloned(), entry.value.get_cloned()));
+                current = entry.next.get();
+            }
+        }
+
+        entries
+    }
+
+    /// Returns the entry for the given key, including how deep in its bucket's chain it is.
+    ///
+    /// Useful for diagnosing hash collisions.
+    pub fn get_entry(&self, key: &Key) -> Option<EntryInfo<Key, Value>> {
+        let mut current = self.bucket(key).get();
+        let mut chain_depth = 1;
+
+        while let Some(entry) = current {
+            if entry.has_key(key) {
+                return Some(EntryInfo {
+                    key: entry.key.get_cloned(),
+                    value: entry.value.get(),
+                    chain_depth,
                 });
-            };
-        };
+            }
+            current = entry.next.get();
+            chain_depth += 1;
+        }
+
+        None
+    }
+
+    /// Returns the length of the longest chain of entries in any bucket.
+    ///
+    /// A value much greater than 1 indicates that the keys' hashes collide often.
+    pub fn max_chain_depth(&self) -> usize {
+        self.chain_lengths().max().unwrap_or(0)
+    }
+
+    /// Returns the amount of buckets with each chain length, where `histogram[i]` is the amount of
+    /// buckets containing `i` entries.
+    ///
+    /// Each bucket is read separately, so entries modified concurrently may or may not be included.
+    pub fn histogram(&self) -> Vec<usize> {
+        let mut histogram = Vec::new();
+
+        for length in self.chain_lengths() {
+            if histogram.len() <= length {
+                histogram.resize(length + 1, 0);
+            }
+            histogram[length] += 1;
+        }
+
+        histogram
+    }
+
+    /// Returns an iterator over the amount of entries in each bucket.
+    fn chain_lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        self.array.iter().map(|bucket| {
+            let mut current = bucket.get();
+            let mut length = 0;
+            while let Some(entry) = current {
+                length += 1;
+                current = entry.next.get();
+            }
+            length
+        })
+    }
+}
+
+/// An entry of a [`CellHashMap`], created by [`CellHashMap::get_entry()`].
+#[derive(Clone, Debug)]
+pub struct EntryInfo<Key, Value> {
+    /// The key of the entry.
+    pub key: Key,
+    /// The value of the entry at the time it was read.
+    pub value: Arc<Value>,
+    /// The position of the entry in its bucket's chain, where 1 is the root of the bucket.
+    pub chain_depth: usize,
+}
+
+/// An iterator over the entries of a [`CellHashMap`], created by [`CellHashMap::iter()`].
+pub struct CellHashMapIter<'a, Key, Value>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+    Value: Debug + Clone + 'static,
+{
+    array: Arc<[Bucket<Key, Value>]>,
+    /// The index of the next bucket to read.
+    index: usize,
+    /// The next entry in the current bucket's chain.
+    current: Option<Arc<CellEntry<Key, Value>>>,
+    map: PhantomData<&'a CellHashMap<Key, Value>>,
+}
+
+impl<Key, Value> Iterator for CellHashMapIter<'_, Key, Value>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+    Value: Debug + Clone + 'static,
+{
+    type Item = (Arc<Key>, Arc<Value>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current.is_none() {
+            self.current = self.array.get(self.index)?.get();
+            self.index += 1;
+        }
+
+        let entry = self.current.take()?;
+        self.current = entry.next.get();
+        Some((entry.key.get(), entry.value.get()))
+    }
+}
+
+impl<Key, Value> FromIterator<(Key, Value)> for CellHashMap<Key, Value>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+    Value: Debug + Clone + 'static,
+{
+    /// Creates a [`CellHashMap`] with enough buckets for the iterator's lower size bound, then
+    /// [`put`](CellHashMap::put())s every pair into it.
+    fn from_iter<Iter: IntoIterator<Item = (Key, Value)>>(iter: Iter) -> Self {
+        let iter = iter.into_iter();
+        let cell_hash_map = Self::with_capacity(iter.size_hint().0);
+
+        for (key, value) in iter {
+            cell_hash_map.put(key, value);
+        }
+
+        cell_hash_map
+    }
+}
+
+impl<Key, Value> From<HashMap<Key, Value>> for CellHashMap<Key, Value>
+where
+    Key: Debug + Hash + Clone + Eq + 'static,
+    Value: Debug + Clone + 'static,
+{
+    /// Idiomatic to calling [`hash_map.into_iter().collect()`](FromIterator::from_iter()).
+    fn from(hash_map: HashMap<Key, Value>) -> Self {
+        hash_map.into_iter().collect()
     }
 }
 
@@ -120,7 +501,9 @@ where
     Key: Debug + Eq + Clone + 'static,
     Value: Debug + Clone + 'static,
 {
-    key: Key,
+    /// The key of the entry. It must never be changed to a key with a different hash, as the entry
+    /// would then be in the wrong bucket.
+    key: Da<Key>,
     value: Da<Value>,
     next: Oda<Self>,
 }
@@ -156,10 +539,10 @@ where
     Key: Debug + Eq + Clone + 'static,
     Value: Debug + Clone + 'static,
 {
-    /// Creates a new [`CellKeyValue<Key, Value>`].
+    /// Creates a new [`CellEntry<Key, Value>`].
     pub fn new(key: Key, value: Value) -> Self {
         Self {
-            key,
+            key: Da::new(key),
             value: Da::new(value),
             next: Oda::default(),
         }
@@ -169,7 +552,7 @@ where
         match (self.key == entry.key, self.next.get()) {
             // This matches.
             (true, _next) => {
-                let cell_entry = Some(Self::new(entry.key, (*self.value.get()).clone()));
+                let cell_entry = Some(Self::new(entry.key.get_cloned(), self.value.get_cloned()));
                 self.value.set((*entry.value.get()).clone());
                 cell_entry
             }
@@ -207,8 +590,13 @@ where
     // }
     // }
 
+    /// Returns `true` if this entry has the given key.
+    fn has_key(&self, key: &Key) -> bool {
+        self.key.as_ref_with(|own_key| own_key == key)
+    }
+
     pub fn get(&self, key: &Key) -> Option<Self> {
-        match (self.key == *key, self.next.get()) {
+        match (self.has_key(key), self.next.get()) {
             (true, _) => Some(self.clone()),
             (false, None) => None,
             (false, Some(next)) => (*next).get(key),
@@ -219,8 +607,7 @@ where
 fn hash_key<Key: Hash>(key: Key) -> u64 {
     let mut hasher = std::hash::DefaultHasher::new();
     key.hash(&mut hasher);
-    let hash_val = std::hash::Hasher::finish(&hasher);
-    hash_val
+    std::hash::Hasher::finish(&hasher)
 }
 
 #[cfg(test)]
@@ -228,14 +615,8 @@ mod tests {
     // use core::panic;
     // use rand::distributions::{Alphanumeric, DistString};
 
-    use core::panic;
-    use std::{
-        thread::{self, sleep},
-        time::Duration,
-    };
-
     use super::*;
-    use crate::dummy_data::*;
+    use crate::test_data::*;
 
     #[test]
     fn retrieve_value() {
@@ -247,6 +628,64 @@ mod tests {
         assert_eq!(*data, Data::default());
     }
 
+    #[test]
+    fn get_or_default() {
+        let cell_hash_map = CellHashMap::new();
+        cell_hash_map.put("test", Data::new(1));
+
+        assert_eq!(*cell_hash_map.get_or_default(&"test"), Data::new(1));
+        assert_eq!(*cell_hash_map.get_or_default(&"missing"), Data::default());
+        assert!(!cell_hash_map.contains_key(&"missing"));
+    }
+
+    #[test]
+    fn get_many() {
+        let cell_hash_map = CellHashMap::new();
+        cell_hash_map.put("a", Data::new(1));
+        cell_hash_map.put("b", Data::new(2));
+
+        let values = cell_hash_map.get_many(&["b", "missing", "a", "b"]);
+        let values: Vec<_> = values
+            .into_iter()
+            .map(|(key, value)| (key, value.map(|value| (*value).clone())))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ("b", Some(Data::new(2))),
+                ("missing", None),
+                ("a", Some(Data::new(1))),
+                ("b", Some(Data::new(2))),
+            ]
+        );
+    }
+
+    #[test]
+    /// A put between two separate gets could make "b" appear newer than "a", which can't happen
+    /// when both are read together, as "a" is always put first.
+    fn get_many_consistent() {
+        let cell_hash_map = CellHashMap::with_capacity(2);
+        cell_hash_map.put("a", 0);
+        cell_hash_map.put("b", 0);
+
+        let writer = {
+            let cell_hash_map = cell_hash_map.clone();
+            std::thread::spawn(move || {
+                for num in 1..5000 {
+                    cell_hash_map.put("a", num);
+                    cell_hash_map.put("b", num);
+                }
+            })
+        };
+
+        while !writer.is_finished() {
+            let values = cell_hash_map.get_many(&["b", "a"]);
+            let (b, a) = (*values[0].1.clone().unwrap(), *values[1].1.clone().unwrap());
+            assert!(a == b || a == b + 1, "a: {a}, b: {b}");
+        }
+        writer.join().unwrap();
+    }
+
     #[test]
     fn overwrite() {
         let cell_hash_map = CellHashMap::new();
@@ -254,11 +693,11 @@ mod tests {
         cell_hash_map.put("test", Data::default());
         let data_one = cell_hash_map.get("test").unwrap();
 
-        let put = cell_hash_map.put("test", 1u64.into());
+        let put = cell_hash_map.put("test", Data::new(1));
         assert_eq!(*data_one, put.unwrap());
         let data_two = cell_hash_map.get("test").unwrap();
 
-        assert_eq!(*data_two, 1u64.into());
+        assert_eq!(*data_two, Data::new(1));
     }
 
     #[test]
@@ -269,7 +708,7 @@ mod tests {
         let data_one = cell_hash_map.get("test").unwrap();
 
         {
-            let replaced = cell_hash_map.put("test", 1u64.into());
+            let replaced = cell_hash_map.put("test", Data::new(1));
             assert_eq!(*data_one, replaced.unwrap());
             // replaced dropped
         }
@@ -277,7 +716,7 @@ mod tests {
 
         let data_two = cell_hash_map.get("test").unwrap();
 
-        assert_eq!(*data_two, 1u64.into());
+        assert_eq!(*data_two, Data::new(1));
     }
 
     fn hash_scoped(string: &str) -> u64 {
@@ -322,14 +761,329 @@ mod tests {
         cell_hash_map.put("e", Data::new(8));
 
         // Other key uneffected
-        // cell_hash_map.remove("e");
-        // assert_eq!(*cell_hash_map.get("test").unwrap(), Data::default());
+        cell_hash_map.remove("e");
+        assert_eq!(*cell_hash_map.get("test").unwrap(), Data::default());
+        assert!(cell_hash_map.get("e").is_none());
 
         // Double remove has no effect
-        // cell_hash_map.remove("e");
-        // assert_eq!(*cell_hash_map.get("test").unwrap(), Data::default());
+        cell_hash_map.remove("e");
+        assert_eq!(*cell_hash_map.get("test").unwrap(), Data::default());
 
+        // Removing from the middle of a chain keeps the rest of the chain.
         cell_hash_map.remove("6KegZ36lLDl73Ke9");
+        assert!(cell_hash_map.get("6KegZ36lLDl73Ke9").is_none());
         assert_eq!(*cell_hash_map.get("test").unwrap(), Data::default());
+        assert_eq!(*cell_hash_map.get("JDbtrFT83atStP2B").unwrap(), 2.into());
+        assert_eq!(*cell_hash_map.get("QWT6GYpvFZxpqTzd").unwrap(), 4.into());
+
+        // Removing the root of a chain keeps the rest of the chain.
+        cell_hash_map.remove("test");
+        assert!(cell_hash_map.get("test").is_none());
+        assert_eq!(*cell_hash_map.get("CQPqhZW1srzeR3hU").unwrap(), 1.into());
+    }
+
+    #[test]
+    fn chain_depth() {
+        let cell_hash_map = CellHashMap::new();
+        assert_eq!(cell_hash_map.max_chain_depth(), 0);
+        assert_eq!(cell_hash_map.histogram(), [DEFAULT_MAX_SIZE as usize]);
+
+        // All of these share a bucket.
+        cell_hash_map.put("test", Data::default());
+        cell_hash_map.put("CQPqhZW1srzeR3hU", Data::new(1));
+        cell_hash_map.put("JDbtrFT83atStP2B", Data::new(2));
+        cell_hash_map.put("a", Data::new(3));
+
+        let root = cell_hash_map.get_entry(&"test").unwrap();
+        assert_eq!(root.chain_depth, 1);
+        assert_eq!(*root.value, Data::default());
+        let chained = cell_hash_map.get_entry(&"JDbtrFT83atStP2B").unwrap();
+        assert_eq!(chained.chain_depth, 3);
+        assert_eq!(*chained.value, Data::new(2));
+        assert_eq!(cell_hash_map.get_entry(&"a").unwrap().chain_depth, 1);
+        assert!(cell_hash_map.get_entry(&"missing").is_none());
+
+        assert_eq!(cell_hash_map.max_chain_depth(), 3);
+        assert_eq!(
+            cell_hash_map.histogram(),
+            [DEFAULT_MAX_SIZE as usize - 2, 1, 0, 1]
+        );
+    }
+
+    #[test]
+    fn remove_entry() {
+        let cell_hash_map = CellHashMap::new();
+        cell_hash_map.put("test", Data::new(1));
+        cell_hash_map.put("CQPqhZW1srzeR3hU", Data::new(2));
+
+        let removed = cell_hash_map.remove_entry("CQPqhZW1srzeR3hU");
+        assert_eq!(removed, Some(("CQPqhZW1srzeR3hU", Data::new(2))));
+        assert!(cell_hash_map.get("CQPqhZW1srzeR3hU").is_none());
+        assert!(cell_hash_map.remove_entry("CQPqhZW1srzeR3hU").is_none());
+        assert_eq!(cell_hash_map.len(), 1);
+    }
+
+    #[test]
+    /// The removed value must be returned, or `None` if the key wasn't present.
+    fn remove_returns_value() {
+        let cell_hash_map = CellHashMap::new();
+        cell_hash_map.put("test", Data::new(1));
+
+        assert!(cell_hash_map.contains_key(&"test"));
+        assert_eq!(cell_hash_map.remove("test"), Some(Data::new(1)));
+        assert!(!cell_hash_map.contains_key(&"test"));
+        assert!(cell_hash_map.remove("test").is_none());
+    }
+
+    #[test]
+    fn iter_and_len() {
+        let cell_hash_map = CellHashMap::new();
+        assert!(cell_hash_map.is_empty());
+
+        // Includes keys sharing a bucket.
+        let keys = ["test", "CQPqhZW1srzeR3hU", "6KegZ36lLDl73Ke9", "a", "b"];
+        for (num, key) in keys.iter().enumerate() {
+            cell_hash_map.put(*key, num);
+        }
+
+        let mut entries: Vec<_> = cell_hash_map
+            .iter()
+            .map(|(key, value)| (*key, *value))
+            .collect();
+        entries.sort();
+        let mut expected: Vec<_> = keys
+            .iter()
+            .enumerate()
+            .map(|(num, key)| (*key, num))
+            .collect();
+        expected.sort();
+
+        assert_eq!(entries, expected);
+        assert_eq!(cell_hash_map.len(), 5);
+        assert!(!cell_hash_map.is_empty());
+
+        // The keys aren't cloned.
+        let (first, _) = cell_hash_map.iter().next().unwrap();
+        let (second, _) = cell_hash_map.iter().next().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    /// The element count must only change when a key is added or removed.
+    fn element_count() {
+        let cell_hash_map = CellHashMap::new();
+
+        cell_hash_map.put("test", 1);
+        cell_hash_map.put("CQPqhZW1srzeR3hU", 2);
+        assert_eq!(cell_hash_map.len(), 2);
+
+        // Overwriting doesn't add an entry.
+        cell_hash_map.put("test", 3);
+        assert_eq!(cell_hash_map.len(), 2);
+
+        cell_hash_map.remove("test");
+        cell_hash_map.remove("test");
+        assert_eq!(cell_hash_map.len(), 1);
+
+        cell_hash_map.remove("CQPqhZW1srzeR3hU");
+        assert!(cell_hash_map.is_empty());
+    }
+
+    #[test]
+    /// The element count must remain accurate under concurrent insertions & removals.
+    fn element_count_concurrent() {
+        let cell_hash_map = CellHashMap::new();
+
+        let handles: Vec<_> = (0..8)
+            .map(|thread| {
+                let cell_hash_map = cell_hash_map.clone();
+                std::thread::spawn(move || {
+                    for num in 0..500 {
+                        cell_hash_map.put(num, thread);
+                        if num % 2 == 0 {
+                            cell_hash_map.remove(num);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Only the odd keys remain.
+        assert_eq!(cell_hash_map.len(), 250);
+        assert_eq!(cell_hash_map.iter().count(), 250);
+    }
+
+    #[test]
+    fn retain() {
+        let cell_hash_map: CellHashMap<String, i32> =
+            (0..600).map(|num| (format!("key {num}"), num)).collect();
+
+        cell_hash_map.retain(|_, value| value % 3 == 0);
+
+        assert_eq!(cell_hash_map.len(), 200);
+        for num in 0..600 {
+            let contained = cell_hash_map.contains_key(&format!("key {num}"));
+            assert_eq!(contained, num % 3 == 0);
+        }
+    }
+
+    #[test]
+    fn from_iter() {
+        let cell_hash_map: CellHashMap<i32, Data> =
+            (0..10).map(|num| (num, Data::new(num))).collect();
+
+        for num in 0..10 {
+            assert_eq!(*cell_hash_map.get(num).unwrap(), num.into());
+        }
+        assert!(cell_hash_map.get(10).is_none());
+    }
+
+    #[test]
+    /// The bucket count must be large enough for the iterator's size.
+    fn from_iter_pre_sizes() {
+        let cell_hash_map: CellHashMap<usize, usize> = (0..1000).map(|num| (num, num)).collect();
+        assert_eq!(cell_hash_map.array.len(), 1024);
+
+        let cell_hash_map: CellHashMap<usize, usize> = (0..10).map(|num| (num, num)).collect();
+        assert_eq!(cell_hash_map.array.len(), DEFAULT_MAX_SIZE as usize);
+    }
+
+    #[test]
+    fn hash_map_round_trip() {
+        let hash_map: HashMap<String, i32> =
+            (0..600).map(|num| (format!("key {num}"), num)).collect();
+
+        let cell_hash_map = CellHashMap::from(hash_map.clone());
+        let round_trip: HashMap<String, i32> =
+            cell_hash_map.collect_entries().into_iter().collect();
+
+        assert_eq!(round_trip, hash_map);
+    }
+
+    #[test]
+    fn update() {
+        let map = CellHashMap::new();
+        map.put("a", 1);
+
+        assert!(map.update(&"a", |value| value + 1));
+        assert!(!map.update(&"b", |value| value + 1));
+
+        assert_eq!(*map.get("a").unwrap(), 2);
+        assert!(map.get("b").is_none());
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn update_or_insert() {
+        let map = CellHashMap::with_capacity(1);
+        map.update_or_insert("a", 10, |value| value + 1);
+        map.update_or_insert("a", 10, |value| value + 1);
+        map.update_or_insert("b", 0, |value| value * 2);
+
+        assert_eq!(*map.get("a").unwrap(), 12);
+        assert_eq!(*map.get("b").unwrap(), 0);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    /// Concurrent updates must never be lost.
+    fn update_concurrent() {
+        let map = CellHashMap::new();
+        map.put(0, 0);
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let map = map.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..500 {
+                        map.update(&0, |value| value + 1);
+                        map.update_or_insert(1, 0, |value| value + 1);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(*map.get(0).unwrap(), 2000);
+        assert_eq!(*map.get(1).unwrap(), 2000);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn clear() {
+        let map: CellHashMap<u32, u32> = (0..300).map(|key| (key, key)).collect();
+        let clone = map.clone();
+        map.clear();
+
+        assert_eq!(clone.len(), 0);
+        assert!(clone.is_empty());
+        assert!((0..300).all(|key| map.get(key).is_none()));
+        assert_eq!(map.iter().count(), 0);
+
+        // The map remains usable.
+        map.put(1, 2);
+        assert_eq!(*clone.get(1).unwrap(), 2);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    /// The element count must match the entries when clearing whilst other threads insert.
+    fn clear_concurrent() {
+        let map = CellHashMap::new();
+
+        let handles: Vec<_> = (0..4)
+            .map(|thread| {
+                let map = map.clone();
+                std::thread::spawn(move || {
+                    for num in 0..500 {
+                        map.put(thread * 500 + num, num);
+                    }
+                })
+            })
+            .collect();
+
+        for _ in 0..20 {
+            map.clear();
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(map.len(), map.iter().count());
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.iter().count(), 0);
+    }
+
+    #[test]
+    /// Holding the lock on one bucket mustn't block operations on other buckets.
+    fn per_bucket_locking() {
+        let map = CellHashMap::new();
+        let locked_key = 0;
+        let other_key = (1..)
+            .find(|key| !std::ptr::eq(map.bucket(key), map.bucket(&locked_key)))
+            .unwrap();
+
+        let _lock = Lock::lock(map.bucket(&locked_key));
+
+        let clone = map.clone();
+        let removed = std::thread::spawn(move || {
+            clone.put(other_key, 1);
+            clone.update(&other_key, |value| value + 1);
+            clone.remove(other_key)
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(removed, Some(2));
+
+        assert_eq!(map.len(), 0);
     }
 }
@@ -1,6 +1,23 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("Either the `std` or `alloc` feature must be enabled");
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod cell_deque;
+#[cfg(feature = "std")]
+pub mod cell_set;
 pub mod data_access;
-// pub mod hash;
+#[cfg(feature = "std")]
+pub mod hash;
+pub mod sync;
+#[cfg(feature = "std")]
 pub mod vec;
 
+#[cfg(feature = "std")]
+pub use cell_set::CellSet;
+
 #[cfg(test)]
 pub(crate) mod test_data;
@@ -0,0 +1,116 @@
+//! Contains the synchronisation primitives used by the data access structs.
+//!
+//! With the `std` feature these are re-exported from [`std::sync`]. Otherwise, with the `alloc`
+//! feature, [`Arc`] is re-exported from `alloc` & [`Mutex`] is a spinlock from the `spin` crate.
+//! With the `parking_lot` feature, [`Mutex`] is instead re-exported from the `parking_lot` crate,
+//! which allows for acquiring a lock with a timeout.
+//!
+//! Neither a spinlock nor a `parking_lot` lock can be poisoned, so a [`PoisonError`] is only ever
+//! returned when using the [`std::sync::Mutex`].
+//!
+//! When compiled with `--cfg loom`, [`Mutex`] is instead re-exported from the `loom` crate, so the
+//! tests in `tests/loom_cell_vec.rs` can explore every interleaving of the locks. [`Arc`] remains
+//! the standard one, as `loom`'s doesn't support weak references.
+
+#[cfg(feature = "std")]
+pub use std::sync::{Arc, OnceLock, Weak};
+#[cfg(all(feature = "std", not(feature = "parking_lot")))]
+pub use std::sync::{LockResult, PoisonError};
+#[cfg(all(feature = "std", not(feature = "parking_lot"), not(loom)))]
+pub use std::sync::{Mutex, MutexGuard};
+
+#[cfg(all(feature = "std", not(feature = "parking_lot"), loom))]
+pub use loom::sync::{Mutex, MutexGuard};
+
+#[cfg(feature = "parking_lot")]
+pub use parking_lot::{Mutex, MutexGuard};
+
+#[cfg(not(feature = "std"))]
+pub use alloc::sync::{Arc, Weak};
+#[cfg(not(feature = "std"))]
+pub use spin::{Mutex, MutexGuard, Once as OnceLock};
+
+/// Mirrors [`std::sync::PoisonError`], which can only be constructed by a [`std::sync::Mutex`].
+#[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+pub struct PoisonError<Guard> {
+    guard: Guard,
+}
+
+#[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+impl<Guard> PoisonError<Guard> {
+    /// Returns the guard of the acquired lock.
+    pub fn into_inner(self) -> Guard {
+        self.guard
+    }
+}
+
+#[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+impl<Guard> core::fmt::Debug for PoisonError<Guard> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PoisonError").finish_non_exhaustive()
+    }
+}
+
+/// Mirrors [`std::sync::LockResult`], for locks which can't be poisoned.
+#[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+pub type LockResult<Guard> = Result<Guard, PoisonError<Guard>>;
+
+/// Acquires the lock on the given [`Mutex`], returning an error if it's poisoned.
+pub(crate) fn lock<Data>(mutex: &Mutex<Data>) -> LockResult<MutexGuard<'_, Data>> {
+    #[cfg(all(feature = "std", not(feature = "parking_lot")))]
+    {
+        mutex.lock()
+    }
+
+    #[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+    {
+        Ok(mutex.lock())
+    }
+}
+
+/// Returns the value of the given [`OnceLock`], initialising it with the given function if it
+/// hasn't been already.
+pub(crate) fn get_or_init<Data>(once: &OnceLock<Data>, init: impl FnOnce() -> Data) -> &Data {
+    #[cfg(feature = "std")]
+    {
+        once.get_or_init(init)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        once.call_once(init)
+    }
+}
+
+/// Acquires the lock on the given [`Mutex`], returning `None` if it couldn't be acquired within
+/// the given timeout.
+#[cfg(feature = "parking_lot")]
+pub(crate) fn lock_timeout<Data>(
+    mutex: &Mutex<Data>,
+    timeout: std::time::Duration,
+) -> Option<MutexGuard<'_, Data>> {
+    mutex.try_lock_for(timeout)
+}
+
+/// Clears the poison from the given [`Mutex`].
+pub(crate) fn clear_poison<Data>(mutex: &Mutex<Data>) {
+    #[cfg(all(feature = "std", not(feature = "parking_lot"), not(loom)))]
+    mutex.clear_poison();
+
+    // The lock can't be poisoned, or with `loom` the poison can't be cleared.
+    #[cfg(any(not(feature = "std"), feature = "parking_lot", loom))]
+    let _ = mutex;
+}
+
+/// Consumes the given [`Mutex`], returning the underlying data even if the lock is poisoned.
+pub(crate) fn into_inner<Data>(mutex: Mutex<Data>) -> Data {
+    #[cfg(all(feature = "std", not(feature = "parking_lot")))]
+    {
+        mutex.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+    {
+        mutex.into_inner()
+    }
+}
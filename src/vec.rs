@@ -1,30 +1,96 @@
-use std::sync::Arc;
+use std::{
+    any::Any,
+    cmp::Ordering,
+    fmt::Debug,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, Index, Range, RangeBounds},
+    sync::Arc,
+};
 
+#[cfg(feature = "arc_swap")]
+use arc_swap::ArcSwap;
 use thiserror::Error;
 
 use crate::{
-    data_access::{Da, Oda},
+    data_access::{locking_mutate::Lock, Da, Oda, SHARED_LOCK_TYPE_MESSAGE},
     locking_mutate,
 };
 
 const EXPECTED_VALUE_MESSAGE: &str = "Expected value inside array bounds";
 
+/// The amount of values read under a single lock when comparing [`CellVec`]s.
+const COMPARE_CHUNK_SIZE: usize = 64;
+
+/// The length of a [`CellVec`], along with the array it was read alongside.
+type LenAndArray<Value> = (usize, Arc<Box<[Oda<Value>]>>);
+
+/// Converts the given range into the indices it covers, returning an error if the range is outside
+/// the given length, or if the start of the range is greater than the end.
+fn resolve_range<Range>(range: &Range, len: usize) -> Result<std::ops::Range<usize>, CellVecErr>
+where
+    Range: RangeBounds<usize>,
+{
+    // A bound of `usize::MAX` that would need incrementing can never be in bounds.
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(CellVecErr::OutOfBounds {
+            index: start,
+            max_bound: len,
+        })?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(CellVecErr::OutOfBounds {
+            index: end,
+            max_bound: len,
+        })?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if end > len {
+        return Err(CellVecErr::OutOfBounds {
+            index: end - 1,
+            max_bound: len,
+        });
+    }
+    if start > end {
+        return Err(CellVecErr::OutOfBounds {
+            index: start,
+            max_bound: end,
+        });
+    }
+
+    Ok(start..end)
+}
+
 #[derive(Error, Debug)]
-enum CellVecErr {
+pub enum CellVecErr {
     #[error("Index out of bounds. Expected {index} (index) < {max_bound}.")]
     OutOfBounds { index: usize, max_bound: usize },
 }
 
-struct CellVec<Value>
+pub struct CellVec<Value>
 where
     Value: 'static,
 {
     /// The index for the allocated capacity of the array on the heap.
+    ///
+    /// The array always has at least this many slots, so values can be pushed without growing it
+    /// until the length reaches the capacity.
     capacity: Da<usize>,
     /// The current highest index of any inserted value.
     len: Da<usize>,
     /// The array which stores the current values.
     array: Da<Box<[Oda<Value>]>>,
+    /// The latest length & array, which can be read without acquiring a lock.
+    ///
+    /// Published together after every change to the array, whilst holding the locks on both. So
+    /// the length always matches the array it's published with, and it's never replaced with an
+    /// outdated pair. Until a change is published, readers see the pair from before the change.
+    #[cfg(feature = "arc_swap")]
+    published: Arc<ArcSwap<LenAndArray<Value>>>,
 }
 
 impl<Value> Clone for CellVec<Value>
@@ -36,7 +102,78 @@ where
             capacity: self.capacity.clone(),
             len: self.len.clone(),
             array: self.array.clone(),
+            #[cfg(feature = "arc_swap")]
+            published: self.published.clone(),
+        }
+    }
+}
+
+impl<Value> Default for CellVec<Value>
+where
+    Value: 'static,
+{
+    /// Idiomatic to calling [`Self::new()`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Value> Debug for CellVec<Value>
+where
+    Value: Debug + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CellVec")
+            .field(&self.snapshot().values)
+            .finish()
+    }
+}
+
+impl<Value> PartialEq for CellVec<Value>
+where
+    Value: PartialEq + 'static,
+{
+    /// Compares the values of both [`CellVec`]s element-by-element.
+    ///
+    /// See [`CellVec::locking_eq()`] for a consistent comparison.
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.len.copy_value();
+        if len != other.len.copy_value() {
+            return false;
         }
+
+        // The locks aren't held between reading each pair of values, so a concurrent modification
+        // to either `CellVec` could result in an inconsistent comparison. Any values removed
+        // during the comparison will be `None` & so only compare equal to other removed values.
+        (0..len).all(|index| self.get(index) == other.get(index))
+    }
+}
+
+impl<Value> Eq for CellVec<Value> where Value: Eq + 'static {}
+
+impl<Value> PartialOrd for CellVec<Value>
+where
+    Value: PartialOrd + 'static,
+{
+    /// Compares the values of both [`CellVec`]s lexicographically, with the shorter [`CellVec`]
+    /// being less if all of its values are equal to the start of the other.
+    ///
+    /// The values are read in chunks, with the locks only being held whilst reading each chunk. So
+    /// a concurrent modification to either `CellVec` could result in an inconsistent comparison.
+    /// Any values removed during the comparison are `None` & so are less than any other values.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare_by(other, |value, other_value| value.partial_cmp(other_value))
+    }
+}
+
+impl<Value> Ord for CellVec<Value>
+where
+    Value: Ord + 'static,
+{
+    /// See [`PartialOrd::partial_cmp()`] for the comparison & locking behaviour.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare_by(other, |value, other_value| Some(value.cmp(other_value)))
+            .expect("Ord values are always comparable")
     }
 }
 
@@ -56,6 +193,36 @@ where
     }
 }
 
+impl<Value> Extend<Value> for CellVec<Value>
+where
+    Value: 'static,
+{
+    /// Appends every value from the given iterator under a single lock.
+    ///
+    /// See [`CellVec::extend_arcs()`] for more information.
+    fn extend<Iter>(&mut self, iter: Iter)
+    where
+        Iter: IntoIterator<Item = Value>,
+    {
+        self.extend_arcs(iter.into_iter().map(Arc::new));
+    }
+}
+
+impl<Value> Extend<Arc<Value>> for CellVec<Value>
+where
+    Value: 'static,
+{
+    /// Appends every [`Arc`] from the given iterator under a single lock.
+    ///
+    /// See [`CellVec::extend_arcs()`] for more information.
+    fn extend<Iter>(&mut self, iter: Iter)
+    where
+        Iter: IntoIterator<Item = Arc<Value>>,
+    {
+        self.extend_arcs(iter);
+    }
+}
+
 impl<Value> CellVec<Value>
 where
     Value: 'static,
@@ -76,54 +243,187 @@ where
 
     /// Creates a new [`CellVec<Value>`] with 0 capacity.
     pub fn new() -> Self {
+        let array = Da::new(Box::new([]) as Box<[Oda<Value>]>);
+
         Self {
             capacity: Da::new(0),
             len: Da::new(0),
-            array: Da::new(Box::new([])),
+            #[cfg(feature = "arc_swap")]
+            published: Arc::new(ArcSwap::from_pointee((0, array.get()))),
+            array,
+        }
+    }
+
+    /// Creates a new [`CellVec<Value>`] with enough capacity to hold the given amount of values
+    /// without growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let array = Da::new((0..capacity).map(|_| Oda::default()).collect::<Box<[_]>>());
+
+        Self {
+            capacity: Da::new(capacity),
+            len: Da::new(0),
+            #[cfg(feature = "arc_swap")]
+            published: Arc::new(ArcSwap::from_pointee((0, array.get()))),
+            array,
+        }
+    }
+
+    /// Returns the latest length & array.
+    ///
+    /// With the `arc_swap` feature, these are read without acquiring the locks on them, as the
+    /// pair that was last published.
+    fn load_array(&self) -> LenAndArray<Value> {
+        #[cfg(feature = "arc_swap")]
+        {
+            let published = self.published.load();
+            (published.0, published.1.clone())
+        }
+
+        #[cfg(not(feature = "arc_swap"))]
+        {
+            (self.len.copy_value(), self.array.get())
+        }
+    }
+
+    /// Publishes the latest length & array to be read by [`Self::load_array()`], which must be
+    /// called after every change to the array.
+    fn publish(&self) {
+        #[cfg(feature = "arc_swap")]
+        {
+            // Locked in the same order as every change, so the pair is read at the same point.
+            let len = Lock::lock(&self.len);
+            let array = Lock::lock(&self.array);
+            self.published.store(Arc::new((**len, array.clone())));
         }
     }
 
     /// Returns the value at the given index.
     /// If the given index is outside the bounds of the array None is returned.
+    ///
+    /// With the `arc_swap` feature, the lock on the array isn't acquired, only the lock on the
+    /// value itself.
+    ///
+    /// There is no unchecked variant, as the length can change between any two calls, so a caller
+    /// could never guarantee that an index is in bounds. Comparing against the length is cheap
+    /// next to acquiring the locks anyway.
     pub fn get(&self, index: usize) -> Option<Arc<Value>> {
-        self.in_bounds(index).ok()?;
+        let (len, array) = self.load_array();
+        if index >= len {
+            return None;
+        }
+
+        // Without the `arc_swap` feature, the array may have been changed since reading the
+        // length, in which case the index may no longer hold a value.
+        array.get(index)?.get()
+    }
+
+    /// Returns the amount of values in the [`CellVec`].
+    ///
+    /// With the `arc_swap` feature, this is the length that was last published alongside the
+    /// array, so it's always in bounds of the array read by [`Self::get()`].
+    pub fn len(&self) -> usize {
+        #[cfg(feature = "arc_swap")]
+        {
+            self.published.load().0
+        }
+
+        #[cfg(not(feature = "arc_swap"))]
+        {
+            self.len.copy_value()
+        }
+    }
+
+    /// Returns `true` if the [`CellVec`] contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
-        Some(
-            (**self.array.get())[index]
-                .get()
-                .expect(EXPECTED_VALUE_MESSAGE),
-        )
+    /// Returns the first value, or `None` if the [`CellVec`] is empty.
+    pub fn first(&self) -> Option<Arc<Value>> {
+        self.get(0)
+    }
+
+    /// Returns the last value, or `None` if the [`CellVec`] is empty.
+    pub fn last(&self) -> Option<Arc<Value>> {
+        self.get(self.len().checked_sub(1)?)
     }
 
     /// Sets the given index to the given value, returning the value that was at that index.
     /// If the given index is outside the bounds of the array None is returned.
+    ///
+    /// The bounds are checked whilst holding the locks on the [`CellVec`], so a concurrent removal
+    /// can't empty the slot in-between.
     pub fn set(&self, index: usize, new_value: Value) -> Option<Arc<Value>> {
-        self.in_bounds(index).ok()?;
+        self.read(|slots| slots.get(index)?.set(new_value))
+    }
+
+    /// Sets each given index to its given value, as a single operation.
+    ///
+    /// The locks on the [`CellVec`] are held whilst setting the values, so no values can be added
+    /// or removed in-between, and [`Self::snapshot()`] sees either all or none of the updates. If
+    /// any index is outside the bounds of the array, an error is returned & no values are set.
+    pub fn batch_set<Iter>(&self, updates: Iter) -> Result<(), CellVecErr>
+    where
+        Iter: IntoIterator<Item = (usize, Value)>,
+    {
+        let updates: Vec<(usize, Value)> = updates.into_iter().collect();
+
+        self.read(|slots| {
+            if let Some(&(index, _)) = updates.iter().find(|(index, _)| *index >= slots.len()) {
+                return Err(CellVecErr::OutOfBounds {
+                    index,
+                    max_bound: slots.len(),
+                });
+            }
+
+            for (index, new_value) in updates {
+                slots[index].set(new_value);
+            }
+            Ok(())
+        })
+    }
 
-        let data = (**self.array.get())[index].clone();
-        Some(data.set(new_value).expect(EXPECTED_VALUE_MESSAGE))
+    /// The same as [`Self::batch_set()`], except that the indices aren't validated beforehand.
+    /// Any update outside the bounds of the array is ignored, whilst the rest are still set.
+    pub fn batch_set_unchecked<Iter>(&self, updates: Iter)
+    where
+        Iter: IntoIterator<Item = (usize, Value)>,
+    {
+        let updates: Vec<(usize, Value)> = updates.into_iter().collect();
+
+        self.read(|slots| {
+            for (index, new_value) in updates {
+                if let Some(slot) = slots.get(index) {
+                    slot.set(new_value);
+                }
+            }
+        });
     }
 
     pub fn push(&self, new_value: Value) {
+        self.push_arc(Arc::new(new_value));
+    }
+
+    /// Appends the given [`Arc`] to the end of the array, without creating a new allocation for
+    /// the value.
+    pub fn push_arc(&self, new_value: Arc<Value>) {
         let closure = |mut len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
             if len >= capacity {
-                // Creates clones of every existing value.
-                let existing_iter = array.iter().map(|value| (*value).clone());
-
-                // Creates new default Oda's to pad the array.
-                let size = capacity.max(1);
-                let default_iter = (0..size).map(|_| -> Oda<Value> { Oda::default() });
-
-                array = existing_iter.chain(default_iter).collect();
-
                 if capacity == 0 {
                     capacity += 1;
                 } else {
                     capacity <<= 1;
                 }
+
+                // Creates clones of every existing value, padding the array up to the capacity.
+                array = array[..len]
+                    .iter()
+                    .cloned()
+                    .chain((len..capacity).map(|_| Oda::default()))
+                    .collect();
             }
 
-            array[len].set(new_value);
+            array[len].set_arc(Some(new_value));
 
             len += 1;
             (len, capacity, array)
@@ -132,33 +432,179 @@ where
         let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
 
         locking_mutate!(len, capacity, array; closure);
+        self.publish();
+    }
+
+    /// Appends every [`Arc`] from the given iterator to the end of the array.
+    ///
+    /// The iterator is consumed before the lock is acquired, then every value is appended under a
+    /// single lock, rather than acquiring it for each value as [`Self::push_arc()`] would. The
+    /// capacity is doubled until it fits the new values.
+    ///
+    /// This is also available through [`Extend`], which requires a mutable reference.
+    pub fn extend_arcs<Iter>(&self, iter: Iter)
+    where
+        Iter: IntoIterator<Item = Arc<Value>>,
+    {
+        let new_values: Vec<Arc<Value>> = iter.into_iter().collect();
+        if new_values.is_empty() {
+            return;
+        }
+
+        let closure = |len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
+            let new_len = len + new_values.len();
+            if new_len > capacity {
+                while capacity < new_len {
+                    capacity = (capacity << 1).max(1);
+                }
+
+                array = array[..len]
+                    .iter()
+                    .cloned()
+                    .chain((len..capacity).map(|_| Oda::default()))
+                    .collect();
+            }
+
+            for (index, new_value) in (len..).zip(new_values) {
+                array[index].set_arc(Some(new_value));
+            }
+
+            (new_len, capacity, array)
+        };
+
+        let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
+
+        locking_mutate!(len, capacity, array; closure);
+        self.publish();
+    }
+
+    /// Returns the amount of values the [`CellVec`] can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.capacity.copy_value()
+    }
+
+    /// Ensures the [`CellVec`] can hold at least the given amount of values in addition to its
+    /// current length without growing.
+    ///
+    /// The capacity is doubled until it fits, so repeated calls don't grow the array each time.
+    pub fn reserve(&self, additional: usize) {
+        let closure = |len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
+            let required = len + additional;
+            if required > capacity {
+                while capacity < required {
+                    capacity = (capacity << 1).max(1);
+                }
+
+                array = array[..len]
+                    .iter()
+                    .cloned()
+                    .chain((len..capacity).map(|_| Oda::default()))
+                    .collect();
+            }
+
+            (len, capacity, array)
+        };
+
+        let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
+
+        locking_mutate!(len, capacity, array; closure);
+        self.publish();
+    }
+
+    /// Reduces the capacity to the current length, removing every unused slot from the array.
+    pub fn shrink_to_fit(&self) {
+        let closure = |len: usize, _capacity: usize, array: Box<[Oda<Value>]>| {
+            let array = array[..len].to_vec().into_boxed_slice();
+            (len, len, array)
+        };
+
+        let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
+
+        locking_mutate!(len, capacity, array; closure);
+        self.publish();
     }
 
+    /// Appends every value of the given [`CellVec`] to the end of this one.
+    ///
+    /// The values of the given [`CellVec`] are read under its lock, then appended under a single
+    /// lock on this one, so appending a [`CellVec`] to itself doesn't deadlock. The values are
+    /// shared rather than cloned, but the two [`CellVec`]s remain independent of each other.
+    pub fn extend_from_cell_vec(&self, other: &CellVec<Value>) {
+        self.extend_arcs(other.collect_arcs());
+    }
+
+    /// Removes the value at the given index & returns it, shifting every value after it down.
+    ///
+    /// The bounds are checked whilst holding the locks on the [`CellVec`], so an error is returned
+    /// if a concurrent removal shrinks the array past the index first.
     pub fn remove(&self, index: usize) -> Result<Arc<Value>, CellVecErr> {
-        self.in_bounds(index)?;
+        // Always overwritten, as the closure is always called.
+        let mut removed = Err(CellVecErr::OutOfBounds {
+            index,
+            max_bound: 0,
+        });
+
+        let mut closure = |mut len: usize, mut capacity: usize, array: Box<[Oda<Value>]>| {
+            match array[..len].get(index).and_then(Oda::get) {
+                Some(value) => removed = Ok(value),
+                None => {
+                    removed = Err(CellVecErr::OutOfBounds {
+                        index,
+                        max_bound: len,
+                    });
+                    return (len, capacity, array);
+                }
+            }
 
-        let mut removed = None;
+            len -= 1;
 
-        let mut closure = |mut len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
-            let (first, second) = array.split_at(index);
-            let (removed_ele, second) = second.split_at(1);
+            if capacity >> 1 >= len {
+                capacity >>= 1;
+            }
+
+            // Compacts the remaining values, padding the array back up to the capacity.
+            let array = array[..index]
+                .iter()
+                .chain(&array[index + 1..len + 1])
+                .cloned()
+                .chain((len..capacity).map(|_| Oda::default()))
+                .collect();
+
+            (len, capacity, array)
+        };
+
+        let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
+
+        locking_mutate!(len, capacity, array; closure);
+        self.publish();
 
-            removed = removed_ele[0].get();
+        removed
+    }
 
-            array = [first, second].concat().into();
+    /// Removes the last value & returns it, or `None` if the [`CellVec`] is empty.
+    ///
+    /// Unlike calling [`Self::remove()`] with the last index, the last value is found whilst the
+    /// locks are held, so a concurrent removal can't cause the wrong value to be removed.
+    pub fn pop(&self) -> Option<Arc<Value>> {
+        let mut popped = None;
 
-            if removed.is_none() {
+        let mut closure = |mut len: usize, mut capacity: usize, mut array: Box<[Oda<Value>]>| {
+            if len == 0 {
                 return (len, capacity, array);
             }
 
             len -= 1;
-
-            // if len == 0 {
-            //     capacity = 0
-            // }
+            popped = array[len].take();
 
             if capacity >> 1 >= len {
                 capacity >>= 1;
+
+                // Shrinks the array down to the new capacity.
+                array = array[..len]
+                    .iter()
+                    .cloned()
+                    .chain((len..capacity).map(|_| Oda::default()))
+                    .collect();
             }
 
             (len, capacity, array)
@@ -167,118 +613,947 @@ where
         let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
 
         locking_mutate!(len, capacity, array; closure);
+        self.publish();
 
-        Ok(removed.expect(EXPECTED_VALUE_MESSAGE))
+        popped
     }
-}
 
-struct CellVecIterator<Value>
-where
-    Value: 'static,
-{
-    cell_vec: CellVec<Value>,
-    index: usize,
-}
+    /// Removes the values within the given range, returning them as an iterator.
+    ///
+    /// The values are removed eagerly under a single lock, so the returned [`Drain`] doesn't hold
+    /// any locks & the values are removed even if the iterator isn't consumed.
+    ///
+    /// An error is returned if the range is outside the bounds of the array, or if the start of
+    /// the range is greater than the end.
+    pub fn drain<Range>(&self, range: Range) -> Result<Drain<'_, Value>, CellVecErr>
+    where
+        Range: RangeBounds<usize>,
+    {
+        let mut drained = Ok(Vec::new());
+
+        let mut closure = |mut len: usize, mut capacity: usize, array: Box<[Oda<Value>]>| {
+            let (start, end) = match resolve_range(&range, len) {
+                Ok(range) => (range.start, range.end),
+                Err(err) => {
+                    drained = Err(err);
+                    return (len, capacity, array);
+                }
+            };
 
-impl<Value> Iterator for CellVecIterator<Value>
-where
-    Value: 'static,
-{
-    type Item = Da<Value>;
+            drained = Ok(array[start..end]
+                .iter()
+                .map(|value| value.get().expect(EXPECTED_VALUE_MESSAGE))
+                .collect());
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let value = self.cell_vec.get(self.index);
-        let value = value.map(|value| Da::acquire(value));
-        self.index += 1;
-        value
-    }
-}
+            len -= end - start;
+            while capacity > 0 && capacity >> 1 >= len {
+                capacity >>= 1;
+            }
 
-#[cfg(test)]
-mod tests {
-    use crate::test_data::Data;
+            // Compacts the remaining values, padding the array back up to the capacity.
+            let array = array[..start]
+                .iter()
+                .chain(&array[end..len + (end - start)])
+                .cloned()
+                .chain((len..capacity).map(|_| Oda::default()))
+                .collect();
 
-    use super::*;
+            (len, capacity, array)
+        };
 
-    fn populate(size: usize) -> CellVec<Data> {
-        let cell_vec: CellVec<Data> = CellVec::new();
+        let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
 
-        for num in 0..size as i32 {
-            cell_vec.push(Data::new(num));
-        }
+        locking_mutate!(len, capacity, array; closure);
+        self.publish();
 
-        // Sanity check
-        assert_eq!(*cell_vec.len.get(), size);
-        cell_vec
+        Ok(Drain {
+            values: drained?.into_iter(),
+            cell_vec: PhantomData,
+        })
     }
 
-    #[test]
-    fn get_bounds_check() {
-        let cell_vec: CellVec<Data> = CellVec::new();
-        assert!(cell_vec.get(0).is_none());
-        assert!(cell_vec.get(20).is_none());
+    /// Replaces the values within the given range with the values from the given iterator,
+    /// returning the removed values as an iterator.
+    ///
+    /// Unlike [`Vec::splice()`], the values are replaced eagerly under a single lock, in the same
+    /// way as [`Self::drain()`], rather than when the returned iterator is dropped. So the returned
+    /// iterator doesn't hold any locks, and the replacement is visible to other threads
+    /// immediately. The given iterator is consumed before the lock is acquired, so it may access
+    /// this [`CellVec`]. The capacity is grown or shrunk to fit the new length.
+    ///
+    /// An error is returned if the range is outside the bounds of the array, or if the start of
+    /// the range is greater than the end.
+    pub fn splice<Range, Iter>(
+        &self,
+        range: Range,
+        replace_with: Iter,
+    ) -> Result<Splice<'_, Value>, CellVecErr>
+    where
+        Range: RangeBounds<usize>,
+        Iter: IntoIterator<Item = Value>,
+    {
+        let replace_with: Vec<Oda<Value>> = replace_with.into_iter().map(Oda::new).collect();
+        let mut removed = Ok(Vec::new());
+
+        let mut closure = |len: usize, mut capacity: usize, array: Box<[Oda<Value>]>| {
+            let (start, end) = match resolve_range(&range, len) {
+                Ok(range) => (range.start, range.end),
+                Err(err) => {
+                    removed = Err(err);
+                    return (len, capacity, array);
+                }
+            };
+
+            removed = Ok(array[start..end]
+                .iter()
+                .map(|value| value.get().expect(EXPECTED_VALUE_MESSAGE))
+                .collect());
+
+            let new_len = len - (end - start) + replace_with.len();
+            while capacity < new_len {
+                capacity = (capacity << 1).max(1);
+            }
+            while capacity > 0 && capacity >> 1 >= new_len {
+                capacity >>= 1;
+            }
+
+            let array = array[..start]
+                .iter()
+                .cloned()
+                .chain(replace_with.iter().cloned())
+                .chain(array[end..len].iter().cloned())
+                .chain((new_len..capacity).map(|_| Oda::default()))
+                .collect();
+
+            (new_len, capacity, array)
+        };
+
+        let (len, capacity, array) = (self.len.clone(), self.capacity.clone(), self.array.clone());
+
+        locking_mutate!(len, capacity, array; closure);
+        self.publish();
+
+        Ok(Drain {
+            values: removed?.into_iter(),
+            cell_vec: PhantomData,
+        })
     }
 
-    #[test]
-    fn push_empty() {
-        let cell_vec = CellVec::new();
-        cell_vec.push(Data::default());
-        assert_eq!(*cell_vec.get(0).unwrap(), Data::default());
+    /// Removes all values from the [`CellVec`].
+    ///
+    /// This is equivalent to draining the full range & dropping the returned iterator.
+    pub fn clear(&self) {
+        self.drain(..).expect("The full range is always in bounds");
     }
 
-    #[test]
-    fn push() {
-        let cell_vec: CellVec<Data> = populate(12);
+    /// Captures the current values of the [`CellVec`] into a [`CellVecSnapshot`], which can be
+    /// indexed into using `snapshot[index]` syntax.
+    ///
+    /// The locks on the [`CellVec`] are held whilst capturing, so the snapshot is consistent. Any
+    /// subsequent modifications to the [`CellVec`] won't be reflected in the snapshot.
+    pub fn snapshot(&self) -> CellVecSnapshot<Value> {
+        let values = self.read(|slots| {
+            slots
+                .iter()
+                .map(|value| value.get().expect(EXPECTED_VALUE_MESSAGE))
+                .collect()
+        });
+
+        CellVecSnapshot { values }
+    }
 
-        for num in 0..12 {
-            assert_eq!(*cell_vec.get(num as usize).unwrap(), num.into());
-        }
+    /// Returns a clone of every value in the [`CellVec`].
+    ///
+    /// The locks on the [`CellVec`] are held whilst reading, so the values are consistent with each
+    /// other. See [`Self::collect_arcs()`] to avoid cloning the values.
+    pub fn collect_values(&self) -> Vec<Value>
+    where
+        Value: Clone,
+    {
+        self.read(|slots| {
+            slots
+                .iter()
+                .map(|value| value.get_cloned().expect(EXPECTED_VALUE_MESSAGE))
+                .collect()
+        })
     }
 
-    #[test]
-    fn remove_bounds_check() {
-        let cell_vec = populate(10);
-        // Within array bounds
-        assert!(cell_vec.remove(9).is_ok());
-        // Out of array bounds
-        assert!(cell_vec.remove(9).is_err());
-        assert!(cell_vec.remove(21).is_err());
+    /// Returns a reference to every value in the [`CellVec`].
+    ///
+    /// See [`Self::collect_values()`] for the locking behaviour.
+    pub fn collect_arcs(&self) -> Vec<Arc<Value>> {
+        self.snapshot().values
     }
 
-    #[test]
-    fn remove_end() {
-        let cell_vec = populate(4);
-        assert_eq!(*cell_vec.remove(3).unwrap(), 3.into());
+    /// Returns the values at each of the given indices, with `None` for any index that is out of
+    /// bounds.
+    ///
+    /// The locks on the [`CellVec`] are held whilst reading every value, so the values are
+    /// consistent with each other, unlike calling [`Self::get()`] for each index.
+    pub fn get_many<const N: usize>(&self, indices: [usize; N]) -> [Option<Arc<Value>>; N] {
+        self.read(|slots| indices.map(|index| slots.get(index).and_then(Oda::get)))
+    }
 
-        assert_eq!(*cell_vec.get(0).unwrap(), 0.into());
-        assert_eq!(*cell_vec.get(1).unwrap(), 1.into());
-        assert_eq!(*cell_vec.get(2).unwrap(), 2.into());
+    /// Returns the values within the given range.
+    ///
+    /// The locks on the [`CellVec`] are held whilst reading every value, so the values are a
+    /// consistent snapshot, unlike calling [`Self::get()`] for each index.
+    ///
+    /// An error is returned if the range is outside the bounds of the array, or if the start of
+    /// the range is greater than the end.
+    pub fn get_range(&self, range: Range<usize>) -> Result<Vec<Arc<Value>>, CellVecErr> {
+        self.read(|slots| {
+            let range = resolve_range(&range, slots.len())?;
+            Ok(slots[range]
+                .iter()
+                .map(|value| value.get().expect(EXPECTED_VALUE_MESSAGE))
+                .collect())
+        })
+    }
 
-        assert!(cell_vec.get(3).is_none());
+    /// Returns clones of the values within the given range.
+    ///
+    /// See [`Self::get_range()`] for more information.
+    pub fn get_range_cloned(&self, range: Range<usize>) -> Result<Vec<Value>, CellVecErr>
+    where
+        Value: Clone,
+    {
+        self.read(|slots| {
+            let range = resolve_range(&range, slots.len())?;
+            Ok(slots[range]
+                .iter()
+                .map(|value| value.get_cloned().expect(EXPECTED_VALUE_MESSAGE))
+                .collect())
+        })
     }
 
-    #[test]
-    fn remove_middle() {
-        let cell_vec = populate(4);
-        assert_eq!(*cell_vec.remove(1).unwrap(), 1.into());
+    /// Returns the values within the given range, with `None` for any index that is out of bounds.
+    ///
+    /// See [`Self::get_many()`] for the locking behaviour.
+    fn read_range(&self, range: Range<usize>) -> Vec<Option<Arc<Value>>> {
+        self.read(|slots| {
+            range
+                .map(|index| slots.get(index).and_then(Oda::get))
+                .collect()
+        })
+    }
 
-        assert_eq!(*cell_vec.get(0).unwrap(), 0.into());
-        assert_eq!(*cell_vec.get(1).unwrap(), 2.into());
-        assert_eq!(*cell_vec.get(2).unwrap(), 3.into());
+    /// Returns the first value the given predicate returns `true` for.
+    ///
+    /// Each value is read separately, with the locks being released in-between. So a concurrent
+    /// modification could cause a value to be missed or an outdated value to be returned. See
+    /// [`Self::locking_find()`] for a consistent search.
+    pub fn find<Func>(&self, predicate: Func) -> Option<Arc<Value>>
+    where
+        Func: Fn(&Value) -> bool,
+    {
+        (0..self.len())
+            .filter_map(|index| self.get(index))
+            .find(|value| predicate(value))
+    }
 
-        assert!(cell_vec.get(3).is_none());
+    /// Returns the index of the first value the given predicate returns `true` for.
+    ///
+    /// See [`Self::find()`] for the locking behaviour.
+    pub fn position<Func>(&self, predicate: Func) -> Option<usize>
+    where
+        Func: Fn(&Value) -> bool,
+    {
+        (0..self.len()).find(|index| self.get(*index).is_some_and(|value| predicate(&value)))
     }
 
-    #[test]
-    fn remove_start() {
-        let cell_vec = populate(4);
-        assert_eq!(*cell_vec.remove(0).unwrap(), 0.into());
+    /// Returns the last value the given predicate returns `true` for.
+    ///
+    /// See [`Self::find()`] for the locking behaviour.
+    pub fn rfind<Func>(&self, predicate: Func) -> Option<Arc<Value>>
+    where
+        Func: Fn(&Value) -> bool,
+    {
+        (0..self.len())
+            .rev()
+            .filter_map(|index| self.get(index))
+            .find(|value| predicate(value))
+    }
 
-        assert_eq!(*cell_vec.get(0).unwrap(), 1.into());
-        assert_eq!(*cell_vec.get(1).unwrap(), 2.into());
-        assert_eq!(*cell_vec.get(2).unwrap(), 3.into());
+    /// Returns the index of the last value the given predicate returns `true` for.
+    ///
+    /// See [`Self::find()`] for the locking behaviour.
+    pub fn rposition<Func>(&self, predicate: Func) -> Option<usize>
+    where
+        Func: Fn(&Value) -> bool,
+    {
+        (0..self.len())
+            .rev()
+            .find(|index| self.get(*index).is_some_and(|value| predicate(&value)))
+    }
 
-        assert!(cell_vec.get(3).is_none());
+    /// Returns the first value the given predicate returns `true` for.
+    ///
+    /// Unlike [`Self::find()`], the locks on the [`CellVec`] are held for the entire search, so no
+    /// values can be modified in-between. The predicate **must not** access this [`CellVec`].
+    pub fn locking_find<Func>(&self, predicate: Func) -> Option<Arc<Value>>
+    where
+        Func: Fn(&Value) -> bool,
+    {
+        self.read(|slots| {
+            slots
+                .iter()
+                .filter_map(Oda::get)
+                .find(|value| predicate(value))
+        })
+    }
+
+    /// Compares the values of both [`CellVec`]s lexicographically with the given function, reading
+    /// the values in chunks to avoid acquiring the locks for every value.
+    ///
+    /// See [`Self::partial_cmp()`] for the comparison & locking behaviour.
+    fn compare_by<Func>(&self, other: &Self, func: Func) -> Option<Ordering>
+    where
+        Func: Fn(&Option<Arc<Value>>, &Option<Arc<Value>>) -> Option<Ordering>,
+    {
+        let (len, other_len) = (self.len(), other.len());
+        let shared_len = len.min(other_len);
+
+        for start in (0..shared_len).step_by(COMPARE_CHUNK_SIZE) {
+            let range = start..shared_len.min(start + COMPARE_CHUNK_SIZE);
+            let values = self.read_range(range.clone());
+            let other_values = other.read_range(range);
+
+            for (value, other_value) in values.iter().zip(&other_values) {
+                match func(value, other_value)? {
+                    Ordering::Equal => continue,
+                    ordering => return Some(ordering),
+                }
+            }
+        }
+
+        Some(len.cmp(&other_len))
+    }
+
+    /// Calls the given function with the slots of the array that are within bounds, whilst
+    /// holding the locks on the length & array.
+    fn read<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(&[Oda<Value>]) -> Output,
+    {
+        let len = Lock::lock(&self.len);
+        let array = Lock::lock(&self.array);
+        func(&array[..**len])
+    }
+
+    /// Sorts the values with the given comparison function.
+    ///
+    /// The locks on the [`CellVec`] are held whilst sorting, so no values can be added or removed
+    /// in-between. Existing references to the values remain valid, as the values themselves are
+    /// moved rather than cloned.
+    pub fn sort_by<Func>(&self, mut compare: Func)
+    where
+        Func: FnMut(&Value, &Value) -> Ordering,
+    {
+        self.read(|slots| {
+            let mut values: Vec<_> = slots
+                .iter()
+                .map(|value| value.get().expect(EXPECTED_VALUE_MESSAGE))
+                .collect();
+
+            values.sort_by(|first, second| compare(first, second));
+
+            for (slot, value) in slots.iter().zip(values) {
+                slot.set_arc(Some(value));
+            }
+        });
+    }
+
+    /// Sorts the values by the key extracted by the given function.
+    ///
+    /// See [`Self::sort_by()`] for the locking behaviour.
+    pub fn sort_by_key<Key, Func>(&self, func: Func)
+    where
+        Key: Ord,
+        Func: Fn(&Value) -> Key,
+    {
+        self.sort_by(|first, second| func(first).cmp(&func(second)));
+    }
+
+    /// Reverses the order of the values in place.
+    ///
+    /// The locks on the [`CellVec`] are held whilst reversing.
+    pub fn reverse(&self) {
+        self.rearrange(|slots| slots.reverse());
+    }
+
+    /// Rotates the values in place, such that the first `mid` values move to the end.
+    ///
+    /// See [`slice::rotate_left()`] for more information.
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than the length of the [`CellVec`].
+    pub fn rotate_left(&self, mid: usize) {
+        let in_bounds = self.rearrange(|slots| {
+            let in_bounds = mid <= slots.len();
+            if in_bounds {
+                slots.rotate_left(mid);
+            }
+            in_bounds
+        });
+
+        // Panics after the locks have been released, so they aren't poisoned.
+        assert!(in_bounds, "mid > len");
+    }
+
+    /// Rotates the values in place, such that the last `k` values move to the front.
+    ///
+    /// See [`slice::rotate_right()`] for more information.
+    ///
+    /// # Panics
+    /// Panics if `k` is greater than the length of the [`CellVec`].
+    pub fn rotate_right(&self, k: usize) {
+        let in_bounds = self.rearrange(|slots| {
+            let in_bounds = k <= slots.len();
+            if in_bounds {
+                slots.rotate_right(k);
+            }
+            in_bounds
+        });
+
+        // Panics after the locks have been released, so they aren't poisoned.
+        assert!(in_bounds, "k > len");
+    }
+
+    /// Calls the given function to rearrange the slots of the array that are within bounds, whilst
+    /// holding the locks on the length & array.
+    fn rearrange<Output, Func>(&self, func: Func) -> Output
+    where
+        Func: FnOnce(&mut [Oda<Value>]) -> Output,
+    {
+        let mut output = None;
+
+        let closure = |len: usize, array: Box<[Oda<Value>]>| {
+            // The slots are shared with the original array, so moving them moves the values.
+            let mut slots = array.into_vec();
+            output = Some(func(&mut slots[..len]));
+            (len, slots.into_boxed_slice())
+        };
+
+        let (len, array) = (self.len.clone(), self.array.clone());
+
+        locking_mutate!(len, array; closure);
+        self.publish();
+
+        output.expect("The closure is always called")
+    }
+
+    /// Creates two new [`CellVec`]s from the values of this [`CellVec`]. The first containing the
+    /// values the given predicate returned `true` for, & the second containing the rest.
+    ///
+    /// The values are cloned into the new [`CellVec`]s, so they don't share any state with this
+    /// [`CellVec`], which is left unmodified.
+    pub fn partition<Func>(&self, predicate: Func) -> (CellVec<Value>, CellVec<Value>)
+    where
+        Value: Clone,
+        Func: Fn(&Value) -> bool,
+    {
+        let (matching, rest) = (CellVec::new(), CellVec::new());
+
+        for value in self.snapshot().iter() {
+            match predicate(value) {
+                true => matching.push((**value).clone()),
+                false => rest.push((**value).clone()),
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// Compares the values of both [`CellVec`]s whilst holding the locks on both of them, so the
+    /// comparison is consistent even if either is being concurrently modified.
+    ///
+    /// The locks are acquired in a consistent order, so comparing the same [`CellVec`]s from
+    /// different threads can't deadlock.
+    pub fn locking_eq(&self, other: &Self) -> bool
+    where
+        Value: PartialEq,
+    {
+        // A clone of this `CellVec` shares its locks, which can't be acquired twice.
+        if self.len.lock_address() == other.len.lock_address() {
+            return true;
+        }
+
+        self.read_both(other, |slots, other_slots| {
+            slots.len() == other_slots.len()
+                && slots
+                    .iter()
+                    .zip(other_slots)
+                    .all(|(slot, other_slot)| slot.get() == other_slot.get())
+        })
+    }
+
+    /// Returns an iterator over all contiguous windows of the given size, with the windows
+    /// overlapping.
+    ///
+    /// The length of the [`CellVec`] is read when this method is called, with the values being
+    /// read as each window is yielded. If values are removed from the [`CellVec`] whilst iterating,
+    /// the iteration will end early.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> Windows<Value> {
+        assert!(size != 0, "Window size must be non-zero");
+
+        Windows {
+            cell_vec: self.clone(),
+            len: self.len.copy_value(),
+            size,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator over non-overlapping chunks of the given size. If the length isn't
+    /// divisible by the size, then the last chunk will be shorter.
+    ///
+    /// See [`Self::windows()`] for the behaviour when the [`CellVec`] is modified whilst iterating.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> Chunks<Value> {
+        assert!(size != 0, "Chunk size must be non-zero");
+
+        Chunks {
+            cell_vec: self.clone(),
+            len: self.len.copy_value(),
+            size,
+            index: 0,
+        }
+    }
+
+    /// Calls the given function with each value, returning a new [`CellVec`] containing the values
+    /// of every returned [`CellVec`] in order.
+    ///
+    /// The values are captured as a snapshot when this is called, rather than lazily. See
+    /// [`CellVec::flatten()`] for the consistency of the returned values.
+    pub fn flat_map<Output, Func>(&self, func: Func) -> CellVec<Output>
+    where
+        Func: Fn(&Value) -> CellVec<Output>,
+    {
+        let flattened = CellVec::new();
+        for value in self.collect_arcs() {
+            flattened.extend_arcs(func(&value).collect_arcs());
+        }
+        flattened
+    }
+
+    /// Returns a new [`CellVec`] containing the value returned by the given function for each pair
+    /// of values at the same index, like [`Iterator::zip()`]. The length is the shorter of the two
+    /// lengths.
+    ///
+    /// Both [`CellVec`]s are read whilst holding all of their locks, so the pairs are a consistent
+    /// snapshot. The locks are released before the function is called. The returned [`CellVec`]
+    /// doesn't share state with either of them.
+    pub fn zip_with<Other, Output, Func>(
+        &self,
+        other: &CellVec<Other>,
+        func: Func,
+    ) -> CellVec<Output>
+    where
+        Other: 'static,
+        Func: Fn(&Value, &Other) -> Output,
+    {
+        let pairs = self.read_pairs(other);
+
+        let zipped = CellVec::with_capacity(pairs.len());
+        zipped.extend_arcs(
+            pairs
+                .iter()
+                .map(|(value, other)| Arc::new(func(value, other))),
+        );
+        zipped
+    }
+
+    /// Returns a new [`CellVec`] containing clones of each pair of values at the same index.
+    ///
+    /// See [`Self::zip_with()`] for more information.
+    pub fn zip<Other>(&self, other: &CellVec<Other>) -> CellVec<(Value, Other)>
+    where
+        Value: Clone,
+        Other: Clone + 'static,
+    {
+        self.zip_with(other, |value, other| (value.clone(), other.clone()))
+    }
+
+    /// Returns each pair of values at the same index, read whilst holding the locks on both
+    /// [`CellVec`]s. The locks are acquired in a consistent order, so concurrent calls can't
+    /// deadlock.
+    fn read_pairs<Other>(&self, other: &CellVec<Other>) -> Vec<(Arc<Value>, Arc<Other>)>
+    where
+        Other: 'static,
+    {
+        // A clone of this `CellVec` shares its locks, which can't be acquired twice.
+        if self.len.lock_address() == other.len.lock_address() {
+            return self.read(|slots| {
+                slots
+                    .iter()
+                    .map(|slot| {
+                        let value = slot.get().expect(EXPECTED_VALUE_MESSAGE);
+                        let other = (&value as &dyn Any)
+                            .downcast_ref::<Arc<Other>>()
+                            .expect(SHARED_LOCK_TYPE_MESSAGE)
+                            .clone();
+                        (value, other)
+                    })
+                    .collect()
+            });
+        }
+
+        self.read_both(other, |slots, other_slots| {
+            slots
+                .iter()
+                .zip(other_slots)
+                .map(|(slot, other_slot)| {
+                    (
+                        slot.get().expect(EXPECTED_VALUE_MESSAGE),
+                        other_slot.get().expect(EXPECTED_VALUE_MESSAGE),
+                    )
+                })
+                .collect()
+        })
+    }
+
+    /// Calls the given function with the values of both [`CellVec`]s, whilst holding the locks on
+    /// both of them. The locks are acquired in a consistent order, so concurrent calls can't
+    /// deadlock.
+    ///
+    /// The [`CellVec`]s mustn't share state, as the same lock can't be acquired twice.
+    fn read_both<Other, Output, Func>(&self, other: &CellVec<Other>, func: Func) -> Output
+    where
+        Other: 'static,
+        Func: FnOnce(&[Oda<Value>], &[Oda<Other>]) -> Output,
+    {
+        if self.len.lock_address() < other.len.lock_address() {
+            let len = Lock::lock(&self.len);
+            let array = Lock::lock(&self.array);
+            let other_len = Lock::lock(&other.len);
+            let other_array = Lock::lock(&other.array);
+            func(&array[..**len], &other_array[..**other_len])
+        } else {
+            let other_len = Lock::lock(&other.len);
+            let other_array = Lock::lock(&other.array);
+            let len = Lock::lock(&self.len);
+            let array = Lock::lock(&self.array);
+            func(&array[..**len], &other_array[..**other_len])
+        }
+    }
+}
+
+impl<Value> CellVec<CellVec<Value>>
+where
+    Value: 'static,
+{
+    /// Returns a new [`CellVec`] containing the values of every inner [`CellVec`] in order.
+    ///
+    /// The values are captured as a snapshot when this is called, rather than lazily. Each
+    /// [`CellVec`] is read under its own lock, so each is consistent, but a concurrent modification
+    /// could change an inner [`CellVec`] in-between reading two of them. The returned [`CellVec`]
+    /// doesn't share state with any of them.
+    pub fn flatten(&self) -> CellVec<Value> {
+        self.flat_map(CellVec::clone)
+    }
+}
+
+/// Returns a new [`CellVec`] containing the values of every given [`CellVec`] in order.
+///
+/// The returned [`CellVec`] is created with enough capacity for every value, and doesn't share
+/// state with any of the given [`CellVec`]s. See [`CellVec::flatten()`] for the consistency of the
+/// returned values.
+pub fn cell_vec_concat<Value>(vecs: &[CellVec<Value>]) -> CellVec<Value>
+where
+    Value: 'static,
+{
+    let concatenated = CellVec::with_capacity(vecs.iter().map(CellVec::len).sum());
+    for cell_vec in vecs {
+        concatenated.extend_from_cell_vec(cell_vec);
+    }
+    concatenated
+}
+
+/// The values of a [`CellVec`] at a point in time, created by [`CellVec::snapshot()`].
+///
+/// [`Index`] isn't implemented on [`CellVec`] directly, as it would have to return a reference to
+/// an [`Arc`] that only lives for the duration of the call. As the snapshot owns its [`Arc`]s, it
+/// can hand out references to them.
+#[derive(Debug)]
+pub struct CellVecSnapshot<Value>
+where
+    Value: 'static,
+{
+    values: Vec<Arc<Value>>,
+}
+
+impl<Value> CellVecSnapshot<Value>
+where
+    Value: 'static,
+{
+    /// Returns the amount of values in the snapshot.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the snapshot contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns an iterator over the values in the snapshot.
+    pub fn iter(&self) -> std::slice::Iter<'_, Arc<Value>> {
+        self.values.iter()
+    }
+}
+
+impl<Value> Index<usize> for CellVecSnapshot<Value>
+where
+    Value: 'static,
+{
+    type Output = Arc<Value>;
+
+    /// Returns the value at the given index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds, the same as [`Vec`].
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+/// An iterator over overlapping windows of a [`CellVec`], created by [`CellVec::windows()`].
+pub struct Windows<Value>
+where
+    Value: 'static,
+{
+    cell_vec: CellVec<Value>,
+    /// The length of the [`CellVec`] when the iterator was created.
+    len: usize,
+    size: usize,
+    index: usize,
+}
+
+impl<Value> Iterator for Windows<Value>
+where
+    Value: 'static,
+{
+    type Item = Vec<Arc<Value>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index + self.size > self.len {
+            return None;
+        }
+
+        let window = (self.index..self.index + self.size)
+            .map(|index| self.cell_vec.get(index))
+            .collect();
+
+        match window {
+            Some(_) => self.index += 1,
+            // A value was removed, so the iteration ends.
+            None => self.index = self.len,
+        }
+        window
+    }
+}
+
+impl<Value> FusedIterator for Windows<Value> where Value: 'static {}
+
+/// An iterator over non-overlapping chunks of a [`CellVec`], created by [`CellVec::chunks()`].
+pub struct Chunks<Value>
+where
+    Value: 'static,
+{
+    cell_vec: CellVec<Value>,
+    /// The length of the [`CellVec`] when the iterator was created.
+    len: usize,
+    size: usize,
+    index: usize,
+}
+
+impl<Value> Iterator for Chunks<Value>
+where
+    Value: 'static,
+{
+    type Item = Vec<Arc<Value>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let end = (self.index + self.size).min(self.len);
+        let chunk = (self.index..end)
+            .map(|index| self.cell_vec.get(index))
+            .collect();
+
+        match chunk {
+            Some(_) => self.index = end,
+            // A value was removed, so the iteration ends.
+            None => self.index = self.len,
+        }
+        chunk
+    }
+}
+
+impl<Value> FusedIterator for Chunks<Value> where Value: 'static {}
+
+/// The values removed by [`CellVec::splice()`].
+///
+/// Unlike [`std::vec::Splice`], the replacement has already happened by the time this is returned,
+/// so this is only an iterator over the removed values, the same as [`Drain`].
+pub type Splice<'a, Value> = Drain<'a, Value>;
+
+/// An iterator over the values removed by [`CellVec::drain()`].
+pub struct Drain<'a, Value>
+where
+    Value: 'static,
+{
+    values: std::vec::IntoIter<Arc<Value>>,
+    cell_vec: PhantomData<&'a CellVec<Value>>,
+}
+
+impl<Value> Iterator for Drain<'_, Value>
+where
+    Value: 'static,
+{
+    type Item = Arc<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<Value> DoubleEndedIterator for Drain<'_, Value>
+where
+    Value: 'static,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.values.next_back()
+    }
+}
+
+impl<Value> ExactSizeIterator for Drain<'_, Value> where Value: 'static {}
+
+pub struct CellVecIterator<Value>
+where
+    Value: 'static,
+{
+    cell_vec: CellVec<Value>,
+    index: usize,
+}
+
+impl<Value> Iterator for CellVecIterator<Value>
+where
+    Value: 'static,
+{
+    type Item = Da<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.cell_vec.get(self.index);
+        let value = value.map(|value| Da::acquire(value));
+        self.index += 1;
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_data::Data;
+
+    use super::*;
+
+    fn populate(size: usize) -> CellVec<Data> {
+        let cell_vec: CellVec<Data> = CellVec::new();
+
+        for num in 0..size as i32 {
+            cell_vec.push(Data::new(num));
+        }
+
+        // Sanity check
+        assert_eq!(*cell_vec.len.get(), size);
+        cell_vec
+    }
+
+    fn from_values<Value>(values: impl IntoIterator<Item = Value>) -> CellVec<Value> {
+        let cell_vec = CellVec::new();
+        cell_vec.extend_arcs(values.into_iter().map(Arc::new));
+        cell_vec
+    }
+
+    #[test]
+    fn get_bounds_check() {
+        let cell_vec: CellVec<Data> = CellVec::new();
+        assert!(cell_vec.get(0).is_none());
+        assert!(cell_vec.get(20).is_none());
+    }
+
+    #[test]
+    fn push_empty() {
+        let cell_vec = CellVec::new();
+        cell_vec.push(Data::default());
+        assert_eq!(*cell_vec.get(0).unwrap(), Data::default());
+    }
+
+    #[test]
+    fn push() {
+        let cell_vec: CellVec<Data> = populate(12);
+
+        for num in 0..12 {
+            assert_eq!(*cell_vec.get(num as usize).unwrap(), num.into());
+        }
+    }
+
+    #[test]
+    fn remove_bounds_check() {
+        let cell_vec = populate(10);
+        // Within array bounds
+        assert!(cell_vec.remove(9).is_ok());
+        // Out of array bounds
+        assert!(cell_vec.remove(9).is_err());
+        assert!(cell_vec.remove(21).is_err());
+    }
+
+    #[test]
+    fn remove_end() {
+        let cell_vec = populate(4);
+        assert_eq!(*cell_vec.remove(3).unwrap(), 3.into());
+
+        assert_eq!(*cell_vec.get(0).unwrap(), 0.into());
+        assert_eq!(*cell_vec.get(1).unwrap(), 1.into());
+        assert_eq!(*cell_vec.get(2).unwrap(), 2.into());
+
+        assert!(cell_vec.get(3).is_none());
+    }
+
+    #[test]
+    fn remove_middle() {
+        let cell_vec = populate(4);
+        assert_eq!(*cell_vec.remove(1).unwrap(), 1.into());
+
+        assert_eq!(*cell_vec.get(0).unwrap(), 0.into());
+        assert_eq!(*cell_vec.get(1).unwrap(), 2.into());
+        assert_eq!(*cell_vec.get(2).unwrap(), 3.into());
+
+        assert!(cell_vec.get(3).is_none());
+    }
+
+    #[test]
+    fn remove_start() {
+        let cell_vec = populate(4);
+        assert_eq!(*cell_vec.remove(0).unwrap(), 0.into());
+
+        assert_eq!(*cell_vec.get(0).unwrap(), 1.into());
+        assert_eq!(*cell_vec.get(1).unwrap(), 2.into());
+        assert_eq!(*cell_vec.get(2).unwrap(), 3.into());
+
+        assert!(cell_vec.get(3).is_none());
     }
 
     #[test]
@@ -354,6 +1629,444 @@ mod tests {
         assert!(cell_vec.set(20, 2.into()).is_none());
     }
 
+    #[test]
+    fn first_last_and_pop() {
+        let cell_vec = populate(3);
+        assert_eq!(cell_vec.len(), 3);
+        assert_eq!(*cell_vec.first().unwrap(), 0.into());
+        assert_eq!(*cell_vec.last().unwrap(), 2.into());
+
+        assert_eq!(*cell_vec.pop().unwrap(), 2.into());
+        assert_eq!(*cell_vec.last().unwrap(), 1.into());
+        assert_eq!(cell_vec.capacity.copy_value(), 2);
+
+        // The popped slot can be reused.
+        cell_vec.push(3.into());
+        assert_eq!(*cell_vec.last().unwrap(), 3.into());
+
+        while cell_vec.pop().is_some() {}
+        assert!(cell_vec.is_empty());
+        assert!(cell_vec.first().is_none());
+        assert!(cell_vec.last().is_none());
+        assert!(cell_vec.pop().is_none());
+    }
+
+    #[test]
+    fn pop_push_cycles_keep_capacity() {
+        let cell_vec = populate(4);
+
+        for _ in 0..5 {
+            for _ in 0..3 {
+                cell_vec.pop();
+                assert_eq!(cell_vec.array.get().len(), cell_vec.capacity());
+            }
+
+            for num in 0..3 {
+                cell_vec.push(num.into());
+                assert_eq!(cell_vec.array.get().len(), cell_vec.capacity());
+            }
+        }
+
+        assert_eq!(cell_vec.len(), 4);
+        assert_eq!(cell_vec.capacity(), 4);
+    }
+
+    #[test]
+    fn get_many_and_range() {
+        let cell_vec = populate(4);
+
+        let [first, last, outside] = cell_vec.get_many([0, 3, 4]);
+        assert_eq!(*first.unwrap(), 0.into());
+        assert_eq!(*last.unwrap(), 3.into());
+        assert!(outside.is_none());
+
+        let range = cell_vec.get_range(1..3).unwrap();
+        assert_eq!(range.len(), 2);
+        assert_eq!(*range[0], 1.into());
+        assert_eq!(*range[1], 2.into());
+        assert_eq!(
+            cell_vec.get_range_cloned(1..3).unwrap(),
+            [1.into(), 2.into()]
+        );
+
+        assert!(cell_vec.get_range(4..4).unwrap().is_empty());
+        assert!(cell_vec.get_range(2..5).is_err());
+        assert!(cell_vec.get_range_cloned(3..6).is_err());
+    }
+
+    #[test]
+    /// The range must be a snapshot, unaffected by values pushed concurrently.
+    fn get_range_concurrent_push() {
+        let cell_vec = populate(4);
+
+        let handle = {
+            let cell_vec = cell_vec.clone();
+            std::thread::spawn(move || {
+                for num in 4..1000 {
+                    cell_vec.push(Data::new(num));
+                }
+            })
+        };
+
+        while !handle.is_finished() {
+            let range = cell_vec.get_range(0..4).unwrap();
+            for (num, value) in range.iter().enumerate() {
+                assert_eq!(**value, (num as i32).into());
+            }
+        }
+        handle.join().unwrap();
+        assert_eq!(cell_vec.get_range(996..1000).unwrap().len(), 4);
+    }
+
+    #[test]
+    /// A removal between individual gets can cause a value to be skipped, which must never happen
+    /// when reading the values together.
+    fn get_many_consistent() -> Result<(), CellVecErr> {
+        let cell_vec = populate(4);
+
+        // Individually getting the values with a removal in-between skips the value `1`.
+        let first = cell_vec.get(0).unwrap();
+        cell_vec.remove(0)?;
+        let second = cell_vec.get(1).unwrap();
+        assert_eq!(*first, 0.into());
+        assert_eq!(*second, 2.into());
+
+        let [first, second] = cell_vec.get_many([0, 1]);
+        assert_eq!(*first.unwrap(), 1.into());
+        assert_eq!(*second.unwrap(), 2.into());
+
+        // Removing from the front concurrently with reading pairs of values.
+        let cell_vec = CellVec::new();
+        (0..2000u32).for_each(|num| cell_vec.push(num));
+
+        let clone = cell_vec.clone();
+        let remover = std::thread::spawn(move || {
+            while clone.len() > 2 {
+                clone.remove(0).unwrap();
+            }
+        });
+
+        while cell_vec.len() > 2 {
+            if let [Some(first), Some(second)] = cell_vec.get_many([0, 1]) {
+                assert_eq!(*first + 1, *second);
+            }
+        }
+
+        remover.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn reverse() {
+        let cell_vec = CellVec::new();
+        (0..5u32).for_each(|num| cell_vec.push(num));
+
+        cell_vec.reverse();
+        assert_eq!(values(&cell_vec), vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn rotate() {
+        let cell_vec = CellVec::new();
+        (0..5u32).for_each(|num| cell_vec.push(num));
+
+        cell_vec.rotate_left(0);
+        assert_eq!(values(&cell_vec), vec![0, 1, 2, 3, 4]);
+
+        cell_vec.rotate_left(2);
+        assert_eq!(values(&cell_vec), vec![2, 3, 4, 0, 1]);
+
+        cell_vec.rotate_right(2);
+        assert_eq!(values(&cell_vec), vec![0, 1, 2, 3, 4]);
+
+        // A full cycle has no effect.
+        cell_vec.rotate_left(5);
+        cell_vec.rotate_right(5);
+        assert_eq!(values(&cell_vec), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    /// Panicking mustn't poison the locks.
+    fn rotate_out_of_bounds() {
+        let cell_vec = CellVec::new();
+        (0..5u32).for_each(|num| cell_vec.push(num));
+
+        let clone = cell_vec.clone();
+        let result = std::thread::spawn(move || clone.rotate_left(6)).join();
+        assert!(result.is_err());
+
+        let rotate = std::panic::AssertUnwindSafe(|| cell_vec.rotate_right(6));
+        assert!(std::panic::catch_unwind(rotate).is_err());
+        assert_eq!(values(&cell_vec), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    /// Concurrent reads must always see every value whilst rotating.
+    fn rotate_concurrent() {
+        let cell_vec = CellVec::new();
+        (0..100u32).for_each(|num| cell_vec.push(num));
+
+        let clone = cell_vec.clone();
+        let rotator = std::thread::spawn(move || (0..1000).for_each(|_| clone.rotate_left(1)));
+
+        while !rotator.is_finished() {
+            assert!(cell_vec.get(99).is_some());
+
+            let mut snapshot = values(&cell_vec);
+            snapshot.sort();
+            assert_eq!(snapshot, (0..100).collect::<Vec<_>>());
+        }
+
+        rotator.join().unwrap();
+        // 1000 rotations by 1 is 10 full cycles.
+        assert_eq!(values(&cell_vec), (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn drain_middle() -> Result<(), CellVecErr> {
+        let cell_vec = populate(6);
+
+        let drained: Vec<_> = cell_vec
+            .drain(1..4)?
+            .map(|value| (*value).clone())
+            .collect();
+        assert_eq!(drained, vec![1.into(), 2.into(), 3.into()]);
+
+        assert_eq!(*cell_vec.get(0).unwrap(), 0.into());
+        assert_eq!(*cell_vec.get(1).unwrap(), 4.into());
+        assert_eq!(*cell_vec.get(2).unwrap(), 5.into());
+        assert!(cell_vec.get(3).is_none());
+        assert_eq!(cell_vec.capacity.copy_value(), 4);
+
+        // The array can still grow after being compacted.
+        cell_vec.push(6.into());
+        cell_vec.push(7.into());
+        assert_eq!(*cell_vec.get(4).unwrap(), 7.into());
+
+        Ok(())
+    }
+
+    #[test]
+    /// Draining the full range must remove every value, just like clearing.
+    fn drain_full() -> Result<(), CellVecErr> {
+        let cell_vec = populate(5);
+
+        let drained = cell_vec.drain(..)?;
+        assert_eq!(drained.len(), 5);
+        assert!(cell_vec.get(0).is_none());
+        assert_eq!(cell_vec.capacity.copy_value(), 0);
+
+        let cell_vec = populate(5);
+        cell_vec.clear();
+        assert!(cell_vec.get(0).is_none());
+        assert_eq!(cell_vec.len.copy_value(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drain_bounds_check() {
+        let cell_vec = populate(4);
+
+        assert!(cell_vec.drain(2..5).is_err());
+        assert!(cell_vec.drain(..=4).is_err());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = cell_vec.drain(3..2);
+        assert!(reversed.is_err());
+
+        // Bounds of usize::MAX mustn't overflow.
+        assert!(cell_vec.drain(..=usize::MAX).is_err());
+        assert!(cell_vec
+            .drain((Bound::Excluded(usize::MAX), Bound::Unbounded))
+            .is_err());
+        assert!(cell_vec.splice(..=usize::MAX, []).is_err());
+
+        // Nothing is removed when out of bounds.
+        assert_eq!(cell_vec.len.copy_value(), 4);
+        assert_eq!(cell_vec.drain(4..).unwrap().len(), 0);
+    }
+
+    #[test]
+    /// The snapshot must be unaffected by later modifications.
+    fn snapshot() -> Result<(), CellVecErr> {
+        let cell_vec = populate(4);
+
+        let snapshot = cell_vec.snapshot();
+        cell_vec.set(0, 10.into());
+        cell_vec.remove(3)?;
+
+        assert_eq!(snapshot.len(), 4);
+        assert_eq!(*snapshot[0], 0.into());
+        assert_eq!(*snapshot[3], 3.into());
+        assert_eq!(*cell_vec.snapshot()[0], 10.into());
+        assert_eq!(snapshot.iter().count(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn collect() {
+        let cell_vec = populate(3);
+        let arcs = cell_vec.collect_arcs();
+        cell_vec.set(0, 10.into());
+
+        assert_eq!(
+            cell_vec.collect_values(),
+            vec![10.into(), 1.into(), 2.into()]
+        );
+        assert_eq!(*arcs[0], 0.into());
+        assert_eq!(arcs.len(), 3);
+        assert!(CellVec::<Data>::new().collect_values().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn snapshot_out_of_bounds() {
+        let snapshot = populate(2).snapshot();
+        let _ = &snapshot[2];
+    }
+
+    #[test]
+    fn debug() {
+        let cell_vec = CellVec::new();
+        (0..4u32).for_each(|num| cell_vec.push(num));
+
+        assert_eq!(format!("{cell_vec:?}"), "CellVec([0, 1, 2, 3])");
+        assert_eq!(format!("{:?}", CellVec::<u32>::new()), "CellVec([])");
+    }
+
+    #[test]
+    fn equality() -> Result<(), CellVecErr> {
+        let (first, second) = (populate(4), populate(4));
+        assert_eq!(first, second);
+        assert!(first.locking_eq(&second));
+        assert!(second.locking_eq(&first));
+        // Comparing against a clone mustn't deadlock.
+        assert!(first.locking_eq(&first.clone()));
+
+        second.set(2, 10.into());
+        assert_ne!(first, second);
+        assert!(!first.locking_eq(&second));
+
+        second.remove(3)?;
+        assert_ne!(first, second);
+        assert!(!first.locking_eq(&second));
+
+        Ok(())
+    }
+
+    /// Collects the values of the given [`CellVec`] into plain values.
+    fn values(cell_vec: &CellVec<u32>) -> Vec<u32> {
+        cell_vec.snapshot().iter().map(|value| **value).collect()
+    }
+
+    #[test]
+    fn sort_by_key() {
+        let cell_vec = CellVec::new();
+        [5u32, 3, 8, 1, 9, 2]
+            .into_iter()
+            .for_each(|num| cell_vec.push(num));
+        let nine = cell_vec.get(4).unwrap();
+
+        cell_vec.sort_by_key(|value| *value);
+        assert_eq!(values(&cell_vec), vec![1, 2, 3, 5, 8, 9]);
+
+        cell_vec.sort_by_key(|value| std::cmp::Reverse(*value));
+        assert_eq!(values(&cell_vec), vec![9, 8, 5, 3, 2, 1]);
+        // The values are moved rather than cloned.
+        assert!(Arc::ptr_eq(&nine, &cell_vec.get(0).unwrap()));
+
+        // The function may capture state that can't be cloned.
+        let offset = std::sync::Mutex::new(0);
+        cell_vec.sort_by_key(move |value| *value + *offset.lock().unwrap());
+        assert_eq!(values(&cell_vec), vec![1, 2, 3, 5, 8, 9]);
+    }
+
+    #[test]
+    /// The partitions must not share any state with the original.
+    fn partition() {
+        let cell_vec = CellVec::new();
+        (0..10u32).for_each(|num| cell_vec.push(num));
+
+        let (even, odd) = cell_vec.partition(|value| value % 2 == 0);
+        assert_eq!(values(&even), vec![0, 2, 4, 6, 8]);
+        assert_eq!(values(&odd), vec![1, 3, 5, 7, 9]);
+
+        even.set(0, 100);
+        odd.push(11);
+        assert_eq!(values(&cell_vec), (0..10).collect::<Vec<_>>());
+    }
+
+    /// Collects the given groups of values into plain values.
+    fn unwrap_groups(groups: impl Iterator<Item = Vec<Arc<u32>>>) -> Vec<Vec<u32>> {
+        groups
+            .map(|group| group.iter().map(|value| **value).collect())
+            .collect()
+    }
+
+    #[test]
+    fn windows() {
+        let cell_vec = CellVec::new();
+        (0..10u32).for_each(|num| cell_vec.push(num));
+
+        let windows = unwrap_groups(cell_vec.windows(3));
+        let expected: Vec<Vec<u32>> = (0..8).map(|start| (start..start + 3).collect()).collect();
+        assert_eq!(windows, expected);
+
+        // Windows larger than the length yield nothing.
+        assert_eq!(cell_vec.windows(11).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be non-zero")]
+    fn windows_zero() {
+        CellVec::<u32>::new().windows(0);
+    }
+
+    #[test]
+    fn chunks() {
+        let cell_vec = CellVec::new();
+        (0..10u32).for_each(|num| cell_vec.push(num));
+
+        let chunks = unwrap_groups(cell_vec.chunks(4));
+        assert_eq!(chunks, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+    }
+
+    #[test]
+    /// Removing values whilst iterating ends the iteration early.
+    fn chunks_shrinking() -> Result<(), CellVecErr> {
+        let cell_vec = CellVec::new();
+        (0..10u32).for_each(|num| cell_vec.push(num));
+
+        let mut chunks = cell_vec.chunks(4);
+        assert!(chunks.next().is_some());
+        cell_vec.drain(4..)?;
+        assert!(chunks.next().is_none());
+
+        // The iteration stays ended, even once the values are added back.
+        (4..10u32).for_each(|num| cell_vec.push(num));
+        assert!(chunks.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    /// Removing values whilst iterating ends the iteration, even if values are added back.
+    fn windows_shrinking() -> Result<(), CellVecErr> {
+        let cell_vec = CellVec::new();
+        (0..10u32).for_each(|num| cell_vec.push(num));
+
+        let mut windows = cell_vec.windows(3);
+        assert!(windows.next().is_some());
+        cell_vec.drain(2..)?;
+        assert!(windows.next().is_none());
+
+        (2..10u32).for_each(|num| cell_vec.push(num));
+        assert!(windows.next().is_none());
+
+        Ok(())
+    }
+
     #[test]
     /// The in_bounds function returns Ok if the index is in bounds & Err if it
     /// is out of bounds.
@@ -366,4 +2079,330 @@ mod tests {
             format!("{err}") == "Index out of bounds. Expected 5 (index) < 5."
         }));
     }
+
+    #[test]
+    /// Sorting must give the same order as sorting the equivalent `Vec`s.
+    fn ord() {
+        let vecs = vec![
+            vec![3u32, 1],
+            vec![],
+            vec![1, 2, 3],
+            vec![1, 2],
+            (0..200).collect(),
+            (0..150).chain([0]).collect(),
+            vec![2],
+            vec![1, 2, 3],
+        ];
+
+        let mut cell_vecs: Vec<CellVec<u32>> = vecs
+            .iter()
+            .map(|vec| {
+                let cell_vec = CellVec::new();
+                vec.iter().for_each(|value| cell_vec.push(*value));
+                cell_vec
+            })
+            .collect();
+        cell_vecs.sort();
+
+        let mut sorted = vecs;
+        sorted.sort();
+        assert_eq!(cell_vecs.iter().map(values).collect::<Vec<_>>(), sorted);
+    }
+
+    #[test]
+    fn partial_ord() {
+        let first = CellVec::new();
+        let second = CellVec::new();
+        first.push(1.0);
+        second.push(f64::NAN);
+
+        assert_eq!(first.partial_cmp(&second), None);
+        assert_eq!(first.partial_cmp(&first), Some(Ordering::Equal));
+
+        second.set(0, 2.0).unwrap();
+        assert!(first < second);
+        second.pop();
+        assert!(first > second);
+    }
+
+    #[test]
+    fn find_and_position() {
+        let cell_vec = CellVec::new();
+        [1u32, 4, 2, 4, 3]
+            .into_iter()
+            .for_each(|value| cell_vec.push(value));
+
+        assert_eq!(cell_vec.find(|value| value % 2 == 0).as_deref(), Some(&4));
+        assert_eq!(cell_vec.position(|value| *value == 4), Some(1));
+        assert_eq!(cell_vec.rfind(|value| *value < 3).as_deref(), Some(&2));
+        assert_eq!(cell_vec.rposition(|value| *value == 4), Some(3));
+        assert_eq!(
+            cell_vec.locking_find(|value| *value > 2).as_deref(),
+            Some(&4)
+        );
+
+        assert!(cell_vec.find(|value| *value > 4).is_none());
+        assert!(cell_vec.position(|value| *value > 4).is_none());
+        assert!(cell_vec.rposition(|value| *value > 4).is_none());
+        assert!(cell_vec.locking_find(|value| *value > 4).is_none());
+        assert!(CellVec::<u32>::new().rfind(|_| true).is_none());
+    }
+
+    #[test]
+    fn splice() -> Result<(), CellVecErr> {
+        let cell_vec = CellVec::new();
+        (0..4u32).for_each(|num| cell_vec.push(num));
+
+        let removed: Vec<_> = cell_vec.splice(1..3, [10, 11, 12, 13, 14])?.collect();
+        assert_eq!(
+            removed.iter().map(|value| **value).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(values(&cell_vec), [0, 10, 11, 12, 13, 14, 3]);
+        assert_eq!(cell_vec.capacity.copy_value(), 8);
+
+        // Shrinks to fit.
+        cell_vec.splice(..6, [])?;
+        assert_eq!(values(&cell_vec), [3]);
+        assert_eq!(cell_vec.capacity.copy_value(), 1);
+
+        // The array must remain usable.
+        cell_vec.push(4);
+        cell_vec.splice(2.., [5])?;
+        assert_eq!(values(&cell_vec), [3, 4, 5]);
+
+        assert!(cell_vec.splice(2..5, [0]).is_err());
+        assert_eq!(values(&cell_vec), [3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    /// Values appended through an [`Arc`] must be retrieved the same as those appended by value.
+    fn push_arc() {
+        let by_value = CellVec::new();
+        let mut by_arc = CellVec::new();
+        (0..5u32).for_each(|num| by_value.push(num));
+
+        let first = Arc::new(0u32);
+        by_arc.push_arc(first.clone());
+        by_arc.extend((1..3u32).map(Arc::new));
+        by_arc.extend(3..5u32);
+
+        assert_eq!(values(&by_value), values(&by_arc));
+        assert_eq!(by_value.capacity.copy_value(), by_arc.capacity.copy_value());
+        // The given allocation is used.
+        assert!(Arc::ptr_eq(&by_arc.get(0).unwrap(), &first));
+    }
+
+    #[test]
+    fn flatten() {
+        let cell_vec = CellVec::new();
+        for size in 0..4 {
+            cell_vec.push(populate(size));
+        }
+
+        let flattened = cell_vec.flatten();
+        assert_eq!(
+            flattened.collect_values(),
+            [0, 0, 1, 0, 1, 2].map(Data::new)
+        );
+
+        // The flattened values don't share state with the inner values.
+        cell_vec.get(1).unwrap().push(Data::new(5));
+        assert_eq!(flattened.len(), 6);
+    }
+
+    #[test]
+    fn flat_map() {
+        let cell_vec = populate(3);
+
+        let flattened = cell_vec.flat_map(|value| {
+            let repeated = CellVec::new();
+            repeated.push(value.clone());
+            repeated.push(value.clone() + 10);
+            repeated
+        });
+        assert_eq!(
+            flattened.collect_values(),
+            [0, 10, 1, 11, 2, 12].map(Data::new)
+        );
+    }
+
+    #[test]
+    /// Either every update must be applied, or none of them.
+    fn batch_set() {
+        let cell_vec = populate(3);
+
+        cell_vec
+            .batch_set([(0, Data::new(5)), (2, Data::new(6))])
+            .unwrap();
+        assert_eq!(cell_vec.collect_values(), [5, 1, 6].map(Data::new));
+
+        let result = cell_vec.batch_set([(1, Data::new(7)), (3, Data::new(8))]);
+        assert!(matches!(
+            result,
+            Err(CellVecErr::OutOfBounds {
+                index: 3,
+                max_bound: 3
+            })
+        ));
+        assert_eq!(cell_vec.collect_values(), [5, 1, 6].map(Data::new));
+
+        cell_vec.batch_set_unchecked([(1, Data::new(7)), (3, Data::new(8))]);
+        assert_eq!(cell_vec.collect_values(), [5, 7, 6].map(Data::new));
+        assert!(cell_vec.get(3).is_none());
+    }
+
+    #[test]
+    /// Indices within the capacity but beyond the length must never return a value.
+    fn get_beyond_len() {
+        let cell_vec = populate(5);
+        assert_eq!(cell_vec.capacity(), 8);
+        assert!(cell_vec.get(5).is_none());
+        assert!(cell_vec.get(8).is_none());
+
+        cell_vec.remove(1).unwrap();
+        cell_vec.pop();
+        cell_vec.drain(..1).unwrap();
+        assert_eq!(cell_vec.collect_values(), [2, 3].map(Data::new));
+        assert!((2..cell_vec.array.get().len()).all(|index| cell_vec.get(index).is_none()));
+    }
+
+    #[test]
+    fn zip_with() {
+        let sum = |first: &Data, second: &i32| first.clone() + *second;
+
+        let zipped = populate(3).zip_with(&from_values([10, 20, 30]), sum);
+        assert_eq!(zipped.collect_values(), [10, 21, 32].map(Data::new));
+
+        // The result is as long as the shorter of the two.
+        let zipped = populate(4).zip_with(&from_values([10, 20]), sum);
+        assert_eq!(zipped.collect_values(), [10, 21].map(Data::new));
+        let zipped = populate(1).zip_with(&from_values([10, 20]), sum);
+        assert_eq!(zipped.collect_values(), [Data::new(10)]);
+    }
+
+    #[test]
+    fn zip() {
+        let cell_vec = populate(2);
+
+        let zipped = cell_vec.zip(&from_values(["a", "b", "c"]));
+        assert_eq!(
+            zipped.collect_values(),
+            [(Data::new(0), "a"), (Data::new(1), "b")]
+        );
+
+        // Zipping with a clone doesn't deadlock.
+        let zipped = cell_vec.zip(&cell_vec.clone());
+        assert_eq!(
+            zipped.collect_values(),
+            [(Data::new(0), Data::new(0)), (Data::new(1), Data::new(1))]
+        );
+
+        // The result doesn't share state with either.
+        cell_vec.push(Data::new(2));
+        assert_eq!(zipped.len(), 2);
+    }
+
+    #[test]
+    /// Pushing up to the capacity must not grow the array.
+    fn with_capacity() {
+        let cell_vec = CellVec::with_capacity(100);
+        assert!(cell_vec.is_empty());
+
+        for num in 0..100 {
+            cell_vec.push(Data::new(num));
+            assert_eq!(cell_vec.capacity(), 100);
+        }
+        assert_eq!(
+            cell_vec.collect_values(),
+            (0..100).map(Data::new).collect::<Vec<_>>()
+        );
+
+        cell_vec.push(Data::new(100));
+        assert_eq!(cell_vec.capacity(), 200);
+    }
+
+    #[test]
+    fn reserve() {
+        let cell_vec = populate(3);
+        assert_eq!(cell_vec.capacity(), 4);
+
+        cell_vec.reserve(1);
+        assert_eq!(cell_vec.capacity(), 4);
+        cell_vec.reserve(6);
+        assert_eq!(cell_vec.capacity(), 16);
+        assert_eq!(cell_vec.collect_values(), [0, 1, 2].map(Data::new));
+
+        for num in 3..9 {
+            cell_vec.push(Data::new(num));
+        }
+        assert_eq!(cell_vec.capacity(), 16);
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let cell_vec = populate(5);
+        assert_eq!(cell_vec.capacity(), 8);
+
+        cell_vec.shrink_to_fit();
+        assert_eq!(cell_vec.capacity(), 5);
+        assert_eq!(cell_vec.array.get().len(), 5);
+        assert_eq!(
+            cell_vec.collect_values(),
+            (0..5).map(Data::new).collect::<Vec<_>>()
+        );
+
+        // The array grows again once it's full.
+        cell_vec.push(Data::new(5));
+        assert_eq!(cell_vec.capacity(), 10);
+        assert_eq!(*cell_vec.last().unwrap(), Data::new(5));
+    }
+
+    #[test]
+    /// The array must remain large enough for the capacity after removing a value.
+    fn remove_then_push() {
+        let cell_vec = populate(4);
+
+        cell_vec.remove(0).unwrap();
+        cell_vec.push(Data::new(4));
+        assert_eq!(cell_vec.collect_values(), [1, 2, 3, 4].map(Data::new));
+    }
+
+    #[test]
+    /// Modifying either the originals or the concatenation must not affect the other.
+    fn concat() {
+        let first = populate(2);
+        let second = populate(3);
+
+        let concatenated = cell_vec_concat(&[first.clone(), CellVec::new(), second.clone()]);
+        assert_eq!(
+            concatenated.collect_values(),
+            [0, 1, 0, 1, 2].map(Data::new)
+        );
+
+        first.push(Data::new(5));
+        second.set(0, Data::new(6));
+        assert_eq!(
+            concatenated.collect_values(),
+            [0, 1, 0, 1, 2].map(Data::new)
+        );
+
+        concatenated.set(1, Data::new(7));
+        concatenated.pop();
+        assert_eq!(first.collect_values(), [0, 1, 5].map(Data::new));
+        assert_eq!(second.collect_values(), [6, 1, 2].map(Data::new));
+    }
+
+    #[test]
+    fn extend_from_cell_vec() {
+        let cell_vec = populate(2);
+
+        cell_vec.extend_from_cell_vec(&populate(3));
+        assert_eq!(cell_vec.collect_values(), [0, 1, 0, 1, 2].map(Data::new));
+
+        // Extending from itself doesn't deadlock.
+        cell_vec.extend_from_cell_vec(&cell_vec.clone());
+        assert_eq!(cell_vec.len(), 10);
+    }
 }
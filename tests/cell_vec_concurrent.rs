@@ -0,0 +1,152 @@
+//! Stress tests [`CellVec`] operations from multiple threads, as a lost update or a stale bounds
+//! check would only show up under contention.
+#![cfg(feature = "std")]
+
+use std::{
+    sync::{Arc, Barrier},
+    thread,
+};
+
+use cell_memory::vec::CellVec;
+
+/// The amount of threads pushing concurrently.
+const THREADS: usize = 8;
+/// The amount of values pushed by each thread.
+const PUSHES: usize = 500;
+
+#[test]
+fn concurrent_push() {
+    let cell_vec = CellVec::new();
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let cell_vec = cell_vec.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                // Starts every thread at once to maximise contention.
+                barrier.wait();
+                for num in 0..PUSHES {
+                    cell_vec.push(thread * PUSHES + num);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(cell_vec.len(), THREADS * PUSHES);
+
+    // Every value must be present exactly once.
+    let mut values: Vec<usize> = (0..cell_vec.len())
+        .map(|index| *cell_vec.get(index).unwrap())
+        .collect();
+    values.sort_unstable();
+    assert!(values.into_iter().eq(0..THREADS * PUSHES));
+}
+
+#[test]
+/// Removing the last value from multiple threads must never panic, with every value being removed
+/// by exactly one thread.
+fn concurrent_remove() {
+    let mut cell_vec = CellVec::new();
+    cell_vec.extend(0..THREADS * PUSHES);
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let cell_vec = cell_vec.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                let mut removed = Vec::new();
+                while let Some(last) = cell_vec.len().checked_sub(1) {
+                    // Another thread may have removed the last value since reading the length.
+                    if let Ok(value) = cell_vec.remove(last) {
+                        removed.push(*value);
+                    }
+                    // Also races the bounds check in set against the removals.
+                    cell_vec.set(last, usize::MAX);
+                }
+                removed
+            })
+        })
+        .collect();
+
+    let removed = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap().len())
+        .sum::<usize>();
+
+    assert!(cell_vec.is_empty());
+    assert_eq!(removed, THREADS * PUSHES);
+}
+
+#[test]
+/// Reading the last value whilst other threads push must always find a value, as the length is
+/// never ahead of the array it's read from.
+fn concurrent_push_get() {
+    let cell_vec = CellVec::new();
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let cell_vec = cell_vec.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                for num in 0..PUSHES {
+                    // Half of the threads read whilst the other half push.
+                    if thread % 2 == 0 {
+                        // Shrinking first makes every push grow the array.
+                        cell_vec.shrink_to_fit();
+                        cell_vec.push(num);
+                    } else if let Some(last) = cell_vec.len().checked_sub(1) {
+                        assert!(cell_vec.get(last).is_some());
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(cell_vec.len(), THREADS / 2 * PUSHES);
+}
+
+#[test]
+/// A removed value must never be read again, even whilst other threads push & remove values.
+fn concurrent_remove_get() {
+    let mut cell_vec = CellVec::new();
+    cell_vec.extend(0..THREADS * PUSHES);
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let cell_vec = cell_vec.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                for num in 0..PUSHES {
+                    // Pushes values which were never in the CellVec, so each value is unique.
+                    cell_vec.push(THREADS * PUSHES + thread * PUSHES + num);
+
+                    let Some(index) = cell_vec.len().checked_sub(1 + num % 4) else {
+                        continue;
+                    };
+                    if let Ok(removed) = cell_vec.remove(index) {
+                        assert_ne!(cell_vec.get(index), Some(removed));
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+//! Explores every interleaving of concurrent [`CellVec`] operations with `loom`, rather than
+//! relying on contention to expose a race.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --test loom_cell_vec`.
+#![cfg(all(loom, feature = "std", not(feature = "parking_lot")))]
+
+use cell_memory::{data_access::Da, locking_mutate, vec::CellVec};
+use loom::thread;
+
+#[test]
+/// Neither push may be lost, whichever order they occur in.
+fn concurrent_push() {
+    loom::model(|| {
+        let cell_vec = CellVec::new();
+
+        let handle = {
+            let cell_vec = cell_vec.clone();
+            thread::spawn(move || cell_vec.push(1))
+        };
+        cell_vec.push(2);
+        handle.join().unwrap();
+
+        let mut values = cell_vec.collect_values();
+        values.sort_unstable();
+        assert_eq!(values, [1, 2]);
+    });
+}
+
+#[test]
+/// The removed value must always be the original, with the pushed value remaining.
+fn concurrent_push_and_remove() {
+    loom::model(|| {
+        let cell_vec = CellVec::new();
+        cell_vec.push(0);
+
+        let handle = {
+            let cell_vec = cell_vec.clone();
+            thread::spawn(move || cell_vec.push(1))
+        };
+        let removed = cell_vec.remove(0).unwrap();
+        handle.join().unwrap();
+
+        assert_eq!(*removed, 0);
+        assert_eq!(cell_vec.collect_values(), [1]);
+    });
+}
+
+#[test]
+/// Only one of two concurrent removals of the last value can succeed, with the other returning an
+/// error rather than panicking.
+fn concurrent_remove_and_remove() {
+    loom::model(|| {
+        let cell_vec = CellVec::new();
+        cell_vec.push(0);
+        cell_vec.push(1);
+
+        let handle = {
+            let cell_vec = cell_vec.clone();
+            thread::spawn(move || cell_vec.remove(1).ok().map(|value| *value))
+        };
+        let removed = cell_vec.remove(1).ok().map(|value| *value);
+        let other_removed = handle.join().unwrap();
+
+        assert_eq!(removed.xor(other_removed), Some(1));
+        assert_eq!(cell_vec.collect_values(), [0]);
+    });
+}
+
+#[test]
+/// A read must see either the value before or after the write, never anything else.
+fn concurrent_get_and_set() {
+    loom::model(|| {
+        let cell_vec = CellVec::new();
+        cell_vec.push(0);
+
+        let handle = {
+            let cell_vec = cell_vec.clone();
+            thread::spawn(move || {
+                cell_vec.set(0, 1);
+            })
+        };
+        let read = *cell_vec.get(0).unwrap();
+        handle.join().unwrap();
+
+        assert!(read == 0 || read == 1, "read: {read}");
+        assert_eq!(*cell_vec.get(0).unwrap(), 1);
+    });
+}
+
+#[test]
+/// Both values are always changed together, so a reader holding both locks must see them equal.
+fn locking_mutate_exclusion() {
+    loom::model(|| {
+        let (first, second) = (Da::new(0), Da::new(0));
+
+        let handle = {
+            let (first, second) = (first.clone(), second.clone());
+            thread::spawn(move || {
+                locking_mutate!(first, second; |first, second| (first + 1, second + 1));
+            })
+        };
+        locking_mutate!(first, second; |first, second| {
+            assert_eq!(first, second);
+            (first, second)
+        });
+        handle.join().unwrap();
+
+        assert_eq!((first.copy_value(), second.copy_value()), (1, 1));
+    });
+}
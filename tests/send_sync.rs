@@ -0,0 +1,65 @@
+//! Asserts the traits implemented by [`Da`] & [`Oda`], so that changes to their internal
+//! representation can't silently change which traits they implement.
+
+use std::{
+    cell::Cell,
+    fmt::Debug,
+    panic::{RefUnwindSafe, UnwindSafe},
+    rc::Rc,
+};
+
+use cell_memory::data_access::{Da, Oda};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+/// Implements neither [`Debug`] nor [`Clone`].
+struct Opaque;
+
+#[test]
+fn send_sync() {
+    assert_impl_all!(Da<u32>: Send, Sync);
+    assert_impl_all!(Oda<u32>: Send, Sync);
+}
+
+#[test]
+/// Sending a `Da` across threads would share its value, so the value must be `Send` & `Sync`.
+fn not_send_sync() {
+    assert_not_impl_any!(Da<Rc<u32>>: Send, Sync);
+    assert_not_impl_any!(Oda<Rc<u32>>: Send, Sync);
+    // `Cell` is `Send` but not `Sync`.
+    assert_not_impl_any!(Da<Cell<u32>>: Send, Sync);
+    assert_not_impl_any!(Oda<Cell<u32>>: Send, Sync);
+}
+
+#[test]
+fn debug() {
+    assert_impl_all!(Da<u32>: Debug);
+    assert_impl_all!(Oda<u32>: Debug);
+    assert_not_impl_any!(Da<Opaque>: Debug);
+    assert_not_impl_any!(Oda<Opaque>: Debug);
+}
+
+#[test]
+/// Clones share the underlying data, so the value never has to be `Clone`.
+fn clone() {
+    assert_impl_all!(Da<Opaque>: Clone);
+    assert_impl_all!(Oda<Opaque>: Clone);
+    assert_impl_all!(Da<Rc<u32>>: Clone);
+}
+
+#[test]
+#[cfg(all(feature = "std", not(feature = "parking_lot")))]
+/// Data can be shared across a `catch_unwind` boundary, as a panic whilst the data is being
+/// modified either poisons the lock or leaves the previous data in place.
+fn unwind_safe() {
+    assert_impl_all!(Da<u32>: UnwindSafe, RefUnwindSafe);
+    assert_impl_all!(Oda<u32>: UnwindSafe, RefUnwindSafe);
+}
+
+#[test]
+#[cfg(any(not(feature = "std"), feature = "parking_lot"))]
+/// The spinlock & `parking_lot` lock aren't poisoned by a panic, so data modified in place could be
+/// left partially modified.
+fn not_unwind_safe() {
+    assert_not_impl_any!(Da<u32>: UnwindSafe, RefUnwindSafe);
+    assert_not_impl_any!(Oda<u32>: UnwindSafe, RefUnwindSafe);
+}